@@ -0,0 +1,100 @@
+use std::error::Error;
+use std::time::Duration;
+use crate::{ParseMode, Part};
+use crate::runner;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    /// Summarizes the given samples, or returns `None` if there are no samples.
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        let mut samples = samples.to_vec();
+        samples.sort();
+
+        let median = match samples.len() {
+            0 => return None,
+            len if len % 2 == 0 => (samples[len / 2 - 1] + samples[len / 2]) / 2,
+            len => samples[len / 2],
+        };
+
+        Some(Stats {
+            min: samples[0],
+            median,
+            max: samples[samples.len() - 1],
+        })
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Benchmark {
+    pub day: u8,
+    pub part: Part,
+    pub iterations: usize,
+    pub parse: Stats,
+    pub solve: Stats,
+}
+
+/// Parses the given input and solves the given part of the given day's puzzle the given number of
+/// times, timing parsing and solving separately. Returns `None` if the day's solution doesn't solve
+/// the given part.
+pub fn benchmark(day: u8, input: &str, part: Part, iterations: usize) -> Result<Option<Benchmark>, Box<dyn Error>> {
+    let mut parse_times = Vec::with_capacity(iterations);
+    let mut solve_times = Vec::with_capacity(iterations);
+
+    for _ in 0..iterations {
+        let day_result = runner::run(day, input, Some(part), ParseMode::Strict)?;
+
+        match day_result.parts.first() {
+            Some(result) => solve_times.push(result.elapsed),
+            None => return Ok(None),
+        }
+
+        parse_times.push(day_result.parse_time);
+    }
+
+    match (Stats::from_samples(&parse_times), Stats::from_samples(&solve_times)) {
+        (Some(parse), Some(solve)) => Ok(Some(Benchmark { day, part, iterations, parse, solve })),
+        _ => Err("Benchmarks require at least one iteration".into()),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn millis(samples: &[u64]) -> Vec<Duration> {
+        samples.iter().map(|&millis| Duration::from_millis(millis)).collect()
+    }
+
+    #[test]
+    fn test_stats_from_samples() {
+        assert_eq!(None, Stats::from_samples(&[]));
+
+        assert_eq!(
+            Some(Stats { min: Duration::from_millis(1), median: Duration::from_millis(3), max: Duration::from_millis(9) }),
+            Stats::from_samples(&millis(&[9, 3, 1]))
+        );
+
+        assert_eq!(
+            Some(Stats { min: Duration::from_millis(1), median: Duration::from_millis(4), max: Duration::from_millis(9) }),
+            Stats::from_samples(&millis(&[9, 5, 1, 3]))
+        );
+    }
+
+    #[test]
+    fn test_benchmark() {
+        let benchmark = benchmark(1, "(()))", Part::Two, 3).unwrap().unwrap();
+
+        assert_eq!(1, benchmark.day);
+        assert_eq!(Part::Two, benchmark.part);
+        assert_eq!(3, benchmark.iterations);
+        assert!(benchmark.solve.min <= benchmark.solve.median && benchmark.solve.median <= benchmark.solve.max);
+
+        assert_eq!(None, super::benchmark(12, "[1]", Part::Two, 3).unwrap());
+    }
+}
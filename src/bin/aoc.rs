@@ -0,0 +1,666 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, Cursor, Read};
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::Duration;
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use serde_json::json;
+use advent_of_code_2015::{ParseMode, Part, Report, Value};
+use advent_of_code_2015::bench::{self, Benchmark, Stats};
+use advent_of_code_2015::day02::{InventoryPlan, OrderSummary, PaperRoll};
+use advent_of_code_2015::day03::{self, CompassMove, Direction, Heatmap, HexMove, Move};
+use advent_of_code_2015::generate;
+use advent_of_code_2015::inputs::InputStore;
+use advent_of_code_2015::runner::{self, PartResult, DAYS};
+use advent_of_code_2015::verify::{Answers, Outcome};
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Solutions to the 2015 Advent of Code puzzles")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve one day's puzzle, or every day's puzzle in sequence
+    Run(RunArgs),
+    /// Solve every day's puzzle and compare the answers to known-good answers
+    Verify(VerifyArgs),
+    /// Repeatedly solve each day's puzzle and report parse and solve times
+    Bench(BenchArgs),
+    /// Generate a synthetic puzzle input of a chosen size for stress testing
+    Generate(GenerateArgs),
+    /// Print the paper and ribbon needed for each present in the day 2 order, with totals
+    Order(OrderArgs),
+    /// Plan which paper rolls and ribbon spools to cut each present's wrapping from for day 2
+    Plan(PlanArgs),
+    /// Summarize the presents each house receives on day 3 and draw the delivery routes
+    Heatmap(HeatmapArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// The day to solve; solves every day if not specified
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=15))]
+    day: Option<u8>,
+
+    /// The part to solve; solves both parts if not specified
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Path to the puzzle input, or "-" to read the puzzle input from standard input
+    #[arg(long, conflicts_with = "value", requires = "day")]
+    input: Option<String>,
+
+    /// Puzzle input given inline, e.g. a key, seed, or password
+    #[arg(long, requires = "day")]
+    value: Option<String>,
+
+    /// Directory containing a dayNN.txt input file for each day and an inline.toml manifest for
+    /// days with inline input; used if no other input is given
+    #[arg(long, default_value = "inputs")]
+    input_dir: PathBuf,
+
+    /// Skip malformed input lines (and report them) instead of failing on the first one
+    #[arg(long)]
+    lenient: bool,
+
+    /// How to print answers
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+
+    /// Also print additional analysis of the input for days that offer it; reads the whole input
+    /// into memory instead of streaming it
+    #[arg(long)]
+    report: bool,
+}
+
+#[derive(Args)]
+struct VerifyArgs {
+    /// Path to a TOML file with a [dayNN] table for each day and part1/part2 answers in each table
+    #[arg(long, default_value = "answers.toml")]
+    answers: PathBuf,
+
+    /// Directory containing a dayNN.txt input file for each day and an inline.toml manifest for
+    /// days with inline input
+    #[arg(long, default_value = "inputs")]
+    input_dir: PathBuf,
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    /// The day to benchmark; benchmarks every day if not specified
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=15))]
+    day: Option<u8>,
+
+    /// The part to benchmark; benchmarks both parts if not specified
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// How many times to parse the input and solve each part
+    #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+    iterations: u32,
+
+    /// Directory containing a dayNN.txt input file for each day and an inline.toml manifest for
+    /// days with inline input
+    #[arg(long, default_value = "inputs")]
+    input_dir: PathBuf,
+
+    /// Write the results as JSON to the given path so they can be compared later
+    #[arg(long)]
+    output: Option<PathBuf>,
+
+    /// A label (e.g. a commit hash) to record alongside the results written with --output
+    #[arg(long, requires = "output")]
+    label: Option<String>,
+
+    /// Compare median solve times to the results in a file previously written with --output
+    #[arg(long)]
+    compare: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct GenerateArgs {
+    /// The day for which to generate input
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=15))]
+    day: u8,
+
+    /// The size of the input, e.g. the number of wires for day 7 or reindeer for day 14; defaults
+    /// to roughly the size of the real puzzle input
+    #[arg(long)]
+    size: Option<usize>,
+
+    /// Seed for the random number generator; the same day, size, and seed always produce the same
+    /// input
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+
+    /// Path to which to write the input; writes to standard output if not specified
+    #[arg(long)]
+    output: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct OrderArgs {
+    /// Path to the day 2 puzzle input, or "-" to read the puzzle input from standard input
+    #[arg(long)]
+    input: Option<String>,
+
+    /// Directory containing a day02.txt input file; used if no other input is given
+    #[arg(long, default_value = "inputs")]
+    input_dir: PathBuf,
+
+    /// Skip malformed input lines (and report them) instead of failing on the first one
+    #[arg(long)]
+    lenient: bool,
+
+    /// How to print the breakdown
+    #[arg(long, value_enum, default_value_t = OrderFormat::Table)]
+    format: OrderFormat,
+}
+
+#[derive(Args)]
+struct PlanArgs {
+    /// Path to the day 2 puzzle input, or "-" to read the puzzle input from standard input
+    #[arg(long)]
+    input: Option<String>,
+
+    /// Directory containing a day02.txt input file; used if no other input is given
+    #[arg(long, default_value = "inputs")]
+    input_dir: PathBuf,
+
+    /// Skip malformed input lines (and report them) instead of failing on the first one
+    #[arg(long)]
+    lenient: bool,
+
+    /// The width and length of each roll of paper in feet, e.g. 3x50
+    #[arg(long, default_value = "3x50")]
+    roll: PaperRoll,
+
+    /// The length of ribbon on each spool in feet
+    #[arg(long, default_value_t = 100)]
+    spool: u32,
+}
+
+#[derive(Args)]
+struct HeatmapArgs {
+    /// Path to the day 3 puzzle input, or "-" to read the puzzle input from standard input
+    #[arg(long)]
+    input: Option<String>,
+
+    /// Directory containing a day03.txt input file; used if no other input is given
+    #[arg(long, default_value = "inputs")]
+    input_dir: PathBuf,
+
+    /// Skip illegal moves (and report them) instead of failing on the first one
+    #[arg(long)]
+    lenient: bool,
+
+    /// How many actors take turns following the moves
+    #[arg(long, default_value_t = NonZeroUsize::MIN)]
+    actors: NonZeroUsize,
+
+    /// Which directions the moves can take
+    #[arg(long, value_enum, default_value_t = Directions::Four)]
+    directions: Directions,
+
+    /// Write the number of presents each house receives as a plain PGM image to the given path
+    #[arg(long)]
+    pgm: Option<PathBuf>,
+
+    /// Write each actor's route as a plain PPM image to the given path
+    #[arg(long)]
+    ppm: Option<PathBuf>,
+}
+
+#[derive(Copy, Clone, ValueEnum)]
+enum Directions {
+    /// North, south, east, and west, written as ^, v, >, and <
+    Four,
+    /// The four-way directions plus diagonals, written as 7, 9, 1, and 3 like a numeric keypad
+    Eight,
+    /// Six directions on a hexagonal grid, written as w, e, d, x, z, and a
+    Hex,
+}
+
+#[derive(Copy, Clone, ValueEnum)]
+enum OrderFormat {
+    /// Aligned columns, followed by totals and statistics for the whole order
+    Table,
+    /// Comma-separated values with a header row
+    Csv,
+}
+
+#[derive(Copy, Clone, ValueEnum)]
+enum Format {
+    /// One human-readable line per part
+    Text,
+    /// One JSON record per part, one record per line
+    Json,
+}
+
+fn main() -> ExitCode {
+    let result = match Cli::parse().command {
+        Command::Run(args) => run(args),
+        Command::Verify(args) => verify(args),
+        Command::Bench(args) => bench(args),
+        Command::Generate(args) => generate(args),
+        Command::Order(args) => order(args),
+        Command::Plan(args) => plan(args),
+        Command::Heatmap(args) => heatmap(args),
+    };
+
+    result.unwrap_or_else(|error| {
+        eprintln!("Error: {}", error);
+        ExitCode::FAILURE
+    })
+}
+
+fn run(args: RunArgs) -> Result<ExitCode, Box<dyn Error>> {
+    let part = args.part.map(Part::try_from).transpose()?;
+    let mode = if args.lenient { ParseMode::Lenient } else { ParseMode::Strict };
+
+    let days: Vec<u8> = match args.day {
+        Some(day) => vec![day],
+        None => DAYS.collect(),
+    };
+
+    for day in days {
+        let mut input = open_input(&args, day)?;
+
+        let (day_result, reports) = if args.report {
+            let mut string = String::new();
+            input.read_to_string(&mut string)?;
+
+            (runner::run(day, &string, part, mode), runner::reports(day, &string, mode)?)
+        } else {
+            (runner::run_streaming(day, &mut input, part, mode), Vec::new())
+        };
+
+        let day_result = day_result.map_err(|error| format!("Could not solve day {}: {}", day, error))?;
+
+        if let (Some(part), true) = (part, day_result.parts.is_empty()) {
+            return Err(format!("No solution for day {} part {}", day, part).into());
+        }
+
+        print_skipped(day, &day_result.skipped);
+
+        for result in day_result.parts {
+            match args.format {
+                Format::Text => print_text(&result),
+                Format::Json => print_json(&result),
+            }
+        }
+
+        for report in reports {
+            match args.format {
+                Format::Text => print_report_text(day, &report),
+                Format::Json => print_report_json(day, &report),
+            }
+        }
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn verify(args: VerifyArgs) -> Result<ExitCode, Box<dyn Error>> {
+    let answers: Answers = fs::read_to_string(&args.answers)
+        .map_err(|error| format!("Could not read answers from {}: {}", args.answers.display(), error))?
+        .parse()?;
+
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+
+    for day in DAYS {
+        let expected_parts: Vec<Part> = answers.parts()
+            .filter(|(answer_day, _)| *answer_day == day)
+            .map(|(_, part)| part)
+            .collect();
+
+        let day_result = InputStore::new(&args.input_dir).resolve(day)
+            .map_err(Box::<dyn Error>::from)
+            .and_then(|input| runner::run(day, &input, None, ParseMode::Strict));
+
+        let day_result = match day_result {
+            Ok(day_result) => day_result,
+            Err(error) if expected_parts.is_empty() => {
+                println!("Day {:02}: skipped ({})", day, error);
+                continue;
+            },
+            Err(error) => {
+                for part in expected_parts {
+                    println!("Day {:02}, part {}: FAIL ({})", day, part, error);
+                    failed += 1;
+                }
+
+                continue;
+            }
+        };
+
+        for result in &day_result.parts {
+            match answers.check(result) {
+                Outcome::Pass => {
+                    println!("Day {:02}, part {}: pass ({})", day, result.part, result.answer);
+                    passed += 1;
+                },
+                Outcome::Fail { expected, actual } => {
+                    println!("Day {:02}, part {}: FAIL (expected {}, got {})", day, result.part, expected, actual);
+                    failed += 1;
+                },
+                Outcome::Missing { actual } => {
+                    println!("Day {:02}, part {}: missing expected answer (got {})", day, result.part, actual);
+                    missing += 1;
+                },
+            }
+        }
+
+        for part in expected_parts {
+            if !day_result.parts.iter().any(|result| result.part == part) {
+                println!("Day {:02}, part {}: FAIL (expected {}, but no solution)", day, part,
+                         answers.expected(day, part).unwrap());
+                failed += 1;
+            }
+        }
+    }
+
+    println!("{} passed, {} failed, {} missing", passed, failed, missing);
+
+    Ok(if failed == 0 { ExitCode::SUCCESS } else { ExitCode::FAILURE })
+}
+
+fn bench(args: BenchArgs) -> Result<ExitCode, Box<dyn Error>> {
+    let parts = match args.part {
+        Some(part) => vec![Part::try_from(part)?],
+        None => vec![Part::One, Part::Two],
+    };
+
+    let days: Vec<u8> = match args.day {
+        Some(day) => vec![day],
+        None => DAYS.collect(),
+    };
+
+    let baseline = args.compare.as_ref()
+        .map(|path| read_baseline(path))
+        .transpose()?;
+
+    let mut benchmarks = Vec::new();
+
+    for day in days {
+        let input = match InputStore::new(&args.input_dir).resolve(day) {
+            Ok(input) => input,
+            Err(error) if args.day.is_none() => {
+                println!("Day {:02}: skipped ({})", day, error);
+                continue;
+            },
+            Err(error) => return Err(error.into()),
+        };
+
+        for &part in &parts {
+            let benchmark = bench::benchmark(day, &input, part, args.iterations as usize)
+                .map_err(|error| format!("Could not solve day {}: {}", day, error))?;
+
+            let Some(benchmark) = benchmark else {
+                if args.part.is_some() {
+                    return Err(format!("No solution for day {} part {}", day, part).into());
+                }
+
+                continue;
+            };
+
+            print!("Day {:02}, part {}: parse {}; solve {}", day, part,
+                   format_stats(&benchmark.parse), format_stats(&benchmark.solve));
+
+            if let Some(previous) = baseline.as_ref().and_then(|baseline| baseline.get(&(day, u8::from(part)))) {
+                let current = benchmark.solve.median.as_nanos() as f64;
+                print!(" ({:+.1}% vs. {:?})", (current - *previous as f64) / *previous as f64 * 100.0,
+                       Duration::from_nanos(*previous));
+            }
+
+            println!();
+            benchmarks.push(benchmark);
+        }
+    }
+
+    if let Some(path) = &args.output {
+        let results = json!({
+            "label": args.label,
+            "results": benchmarks.iter().map(benchmark_json).collect::<Vec<_>>(),
+        });
+
+        fs::write(path, format!("{:#}\n", results))
+            .map_err(|error| format!("Could not write results to {}: {}", path.display(), error))?;
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn generate(args: GenerateArgs) -> Result<ExitCode, Box<dyn Error>> {
+    let size = args.size.unwrap_or_else(|| generate::default_size(args.day));
+    let input = generate::generate(args.day, size, args.seed)?;
+
+    match &args.output {
+        Some(path) => fs::write(path, input)
+            .map_err(|error| format!("Could not write input to {}: {}", path.display(), error))?,
+        None => print!("{}", input),
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn order(args: OrderArgs) -> Result<ExitCode, Box<dyn Error>> {
+    let mode = if args.lenient { ParseMode::Lenient } else { ParseMode::Strict };
+    let mut input = open_file_or_store(args.input.as_deref(), &args.input_dir, 2)?;
+
+    let order = OrderSummary::read(&mut input, mode)
+        .map_err(|error| format!("Could not read order: {}", error))?;
+
+    print_skipped(2, &order.skipped);
+
+    let mut out = io::stdout().lock();
+
+    match args.format {
+        OrderFormat::Table => order.input.write_table(&mut out)?,
+        OrderFormat::Csv => order.input.write_csv(&mut out)?,
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn plan(args: PlanArgs) -> Result<ExitCode, Box<dyn Error>> {
+    let mode = if args.lenient { ParseMode::Lenient } else { ParseMode::Strict };
+    let mut input = open_file_or_store(args.input.as_deref(), &args.input_dir, 2)?;
+
+    let order = OrderSummary::read(&mut input, mode)
+        .map_err(|error| format!("Could not read order: {}", error))?;
+
+    print_skipped(2, &order.skipped);
+
+    InventoryPlan::new(&order.input, args.roll, args.spool)?
+        .write(&mut io::stdout().lock())?;
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn heatmap(args: HeatmapArgs) -> Result<ExitCode, Box<dyn Error>> {
+    let mut input = String::new();
+    open_file_or_store(args.input.as_deref(), &args.input_dir, 3)?.read_to_string(&mut input)?;
+
+    let heatmap = match args.directions {
+        Directions::Four => heatmap_of::<Move>(&input, &args),
+        Directions::Eight => heatmap_of::<CompassMove>(&input, &args),
+        Directions::Hex => heatmap_of::<HexMove>(&input, &args),
+    }?;
+
+    for report in heatmap.reports() {
+        print_report_text(3, &report);
+    }
+
+    if let Some(path) = &args.pgm {
+        write_image(path, |out| heatmap.write_pgm(out))?;
+    }
+
+    if let Some(path) = &args.ppm {
+        write_image(path, |out| heatmap.write_ppm(out))?;
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn heatmap_of<D: Direction>(input: &str, args: &HeatmapArgs) -> Result<Heatmap, Box<dyn Error>> {
+    let mode = if args.lenient { ParseMode::Lenient } else { ParseMode::Strict };
+
+    let (moves, skipped) = day03::parse_moves::<D>(input, mode)
+        .map_err(|error| format!("Could not read moves: {}", error))?;
+
+    let skipped: Vec<Box<dyn Error>> = skipped.into_iter()
+        .map(|error| Box::new(error) as Box<dyn Error>)
+        .collect();
+
+    print_skipped(3, &skipped);
+
+    Ok(Heatmap::new(&moves, args.actors))
+}
+
+fn write_image(path: &Path, write: impl FnOnce(&mut dyn io::Write) -> io::Result<()>) -> Result<(), Box<dyn Error>> {
+    let file = File::create(path)
+        .map_err(|error| format!("Could not write image to {}: {}", path.display(), error))?;
+
+    let mut out = io::BufWriter::new(file);
+    write(&mut out)?;
+
+    Ok(io::Write::flush(&mut out)?)
+}
+
+fn print_skipped(day: u8, skipped: &[Box<dyn Error>]) {
+    if !skipped.is_empty() {
+        eprintln!("Day {:02}: skipped {} malformed line(s)", day, skipped.len());
+
+        for error in skipped {
+            eprintln!("    {}", error);
+        }
+    }
+}
+
+fn format_stats(stats: &Stats) -> String {
+    format!("min {:?}, median {:?}, max {:?}", stats.min, stats.median, stats.max)
+}
+
+fn benchmark_json(benchmark: &Benchmark) -> serde_json::Value {
+    let stats_json = |stats: &Stats| json!({
+        "min": stats.min.as_nanos() as u64,
+        "median": stats.median.as_nanos() as u64,
+        "max": stats.max.as_nanos() as u64,
+    });
+
+    json!({
+        "day": benchmark.day,
+        "part": u8::from(benchmark.part),
+        "iterations": benchmark.iterations,
+        "parse_ns": stats_json(&benchmark.parse),
+        "solve_ns": stats_json(&benchmark.solve),
+    })
+}
+
+/// Reads the median solve time, in nanoseconds, for each day and part from a file previously
+/// written by the bench command.
+fn read_baseline(path: &Path) -> Result<HashMap<(u8, u8), u64>, Box<dyn Error>> {
+    let contents = fs::read_to_string(path)
+        .map_err(|error| format!("Could not read benchmark results from {}: {}", path.display(), error))?;
+
+    let results: serde_json::Value = serde_json::from_str(&contents)?;
+    let malformed = || format!("Unexpected benchmark results format in {}", path.display());
+
+    results["results"].as_array()
+        .ok_or_else(malformed)?
+        .iter()
+        .map(|result| {
+            let day = result["day"].as_u64().and_then(|day| u8::try_from(day).ok());
+            let part = result["part"].as_u64().and_then(|part| u8::try_from(part).ok());
+            let median = result["solve_ns"]["median"].as_u64();
+
+            match (day, part, median) {
+                (Some(day), Some(part), Some(median)) => Ok(((day, part), median)),
+                _ => Err(malformed().into()),
+            }
+        })
+        .collect()
+}
+
+fn open_input(args: &RunArgs, day: u8) -> Result<Box<dyn BufRead>, Box<dyn Error>> {
+    match &args.value {
+        Some(value) => Ok(Box::new(Cursor::new(value.clone().into_bytes()))),
+        None => open_file_or_store(args.input.as_deref(), &args.input_dir, day),
+    }
+}
+
+/// Opens the given path, or standard input if the path is "-", falling back to the day's input in
+/// the given input directory if no path is given.
+fn open_file_or_store(path: Option<&str>, input_dir: &Path, day: u8) -> Result<Box<dyn BufRead>, Box<dyn Error>> {
+    match path {
+        Some("-") => Ok(Box::new(io::stdin().lock())),
+        Some(path) => {
+            let file = File::open(path).map_err(|error| format!("Could not read {}: {}", path, error))?;
+            Ok(Box::new(BufReader::new(file)))
+        },
+        None => Ok(InputStore::new(input_dir).open(day)?),
+    }
+}
+
+fn print_text(result: &PartResult) {
+    print!("Day {:02}, part {}: {}", result.day, result.part, result.answer);
+
+    if !result.answer.details.is_empty() {
+        let details: Vec<String> = result.answer.details.iter()
+            .map(|(name, value)| format!("{}: {}", name, value))
+            .collect();
+
+        print!(" ({})", details.join("; "));
+    }
+
+    println!();
+}
+
+fn print_json(result: &PartResult) {
+    let details: serde_json::Map<String, serde_json::Value> = result.answer.details.iter()
+        .map(|(name, value)| (name.to_string(), json_value(value)))
+        .collect();
+
+    println!("{}", json!({
+        "day": result.day,
+        "part": u8::from(result.part),
+        "answer": json_value(&result.answer.value),
+        "details": details,
+        "elapsed_ms": result.elapsed.as_secs_f64() * 1000.0,
+    }));
+}
+
+fn print_report_text(day: u8, report: &Report) {
+    println!("Day {:02}, {}:", day, report.title);
+
+    for (label, value) in &report.entries {
+        println!("    {}: {}", label, value);
+    }
+}
+
+fn print_report_json(day: u8, report: &Report) {
+    let entries: Vec<serde_json::Value> = report.entries.iter()
+        .map(|(label, value)| json!({ "label": label, "value": json_value(value) }))
+        .collect();
+
+    println!("{}", json!({
+        "day": day,
+        "report": report.title,
+        "entries": entries,
+    }));
+}
+
+fn json_value(value: &Value) -> serde_json::Value {
+    match value {
+        Value::Integer(value) => json!(value),
+        Value::Text(value) => json!(value),
+        Value::List(values) => values.iter().map(json_value).collect(),
+    }
+}
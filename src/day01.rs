@@ -0,0 +1,505 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io::BufRead;
+use crate::{error, stream, Answer, Lenient, ParseMode, Report, Part, Solution, Streamed, Streaming};
+use crate::stream::Piece;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input, Box<dyn Error>> {
+        let input = input.trim_end();
+
+        // Any byte that isn't a parenthesis starts an illegal character, since parentheses are
+        // never part of multi-byte characters
+        if let Some(i) = input.bytes().position(|b| b != b'(' && b != b')') {
+            let c = input[i..].chars().next().unwrap();
+            return Err(Box::new(ParseError::new(ErrorKind::IllegalDirection(c), input, &input[i..i + c.len_utf8()])));
+        }
+
+        Ok(String::from(input))
+    }
+
+    fn parse_lenient(input: &str) -> Result<Lenient<Self::Input>, Box<dyn Error>> {
+        let input = input.trim_end();
+
+        let mut directions = String::with_capacity(input.len());
+        let mut skipped = Vec::new();
+
+        for (i, c) in input.char_indices() {
+            if is_direction(c) {
+                directions.push(c);
+            } else {
+                skipped.push(ParseError::new(ErrorKind::IllegalDirection(c), input, &input[i..i + c.len_utf8()]));
+            }
+        }
+
+        Ok(Lenient::new(directions, skipped))
+    }
+
+    fn part_one(directions: &Self::Input) -> Answer {
+        get_floor(directions).into()
+    }
+
+    fn part_two(directions: &Self::Input) -> Option<Answer> {
+        Some(basement_answer(find_first_basement_position(directions)))
+    }
+
+    fn reports(directions: &Self::Input) -> Vec<Report> {
+        let trace = FloorTrace::new(directions);
+        let (highest, lowest) = (trace.highest(), trace.lowest());
+
+        vec![
+            Report {
+                title: "Extremes",
+                entries: vec![
+                    (String::from("highest floor"), highest.floor.into()),
+                    (String::from("highest floor steps"), highest.steps.into()),
+                    (String::from("lowest floor"), lowest.floor.into()),
+                    (String::from("lowest floor steps"), lowest.steps.into()),
+                ],
+            },
+            Report {
+                title: "First step on each floor",
+                entries: trace.first_steps().into_iter()
+                    .map(|(floor, step)| (format!("floor {}", floor), step.into()))
+                    .collect(),
+            },
+            Report {
+                title: "Steps spent on each floor",
+                entries: trace.steps_per_floor().into_iter()
+                    .map(|(floor, steps)| (format!("floor {}", floor), steps.into()))
+                    .collect(),
+            },
+        ]
+    }
+}
+
+impl Streaming for Day01 {
+    fn solve_streaming(input: &mut dyn BufRead, part: Option<Part>, mode: ParseMode) -> Result<Streamed, Box<dyn Error>> {
+        let mut progress = Progress::default();
+        let mut skipped = Vec::new();
+
+        stream::for_each_piece_trim_end(input, is_direction_byte, |offset, piece| -> Result<(), Box<dyn Error>> {
+            match piece {
+                Piece::Run(directions) => progress.follow(directions),
+                Piece::Char(c) if is_direction(c) => progress.follow(&[c as u8]),
+                Piece::Char(c) => {
+                    let error = ParseError { kind: ErrorKind::IllegalDirection(c), line: 1, column: offset + 1, text: c.to_string() };
+
+                    match mode {
+                        ParseMode::Strict => return Err(Box::new(error)),
+                        ParseMode::Lenient => skipped.push(error),
+                    }
+                },
+            }
+
+            Ok(())
+        })?;
+
+        Ok(Streamed {
+            part_one: Part::One.is_selected(part).then(|| progress.floor.into()),
+            part_two: Part::Two.is_selected(part).then(|| basement_answer(progress.first_basement_position)),
+            skipped: error::boxed(skipped),
+        })
+    }
+}
+
+pub type ParseError = error::ParseError<ErrorKind>;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ErrorKind {
+    IllegalDirection(char),
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ErrorKind::IllegalDirection(c) => write!(f, "Illegal direction {:?}", c),
+        }
+    }
+}
+
+fn basement_answer(position: Option<usize>) -> Answer {
+    match position {
+        Some(position) => position.into(),
+        None => String::from("never enters basement").into(),
+    }
+}
+
+fn is_direction(c: char) -> bool {
+    c == '(' || c == ')'
+}
+
+fn is_direction_byte(b: u8) -> bool {
+    b == b'(' || b == b')'
+}
+
+fn floor_change(c: char) -> i32 {
+    match c {
+        '(' => 1,
+        ')' => -1,
+        _ => 0
+    }
+}
+
+/// The number of bytes of directions summarized at a time. Small enough that per-chunk counts fit
+/// in a `u8`, which lets the compiler count many bytes per instruction.
+const CHUNK_SIZE: usize = 128;
+
+/// Santa's progress through directions that are followed a chunk at a time.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+struct Progress {
+    floor: i32,
+    steps: usize,
+    first_basement_position: Option<usize>,
+}
+
+impl Progress {
+    /// Follows the given directions, ignoring any bytes other than parentheses.
+    fn follow(&mut self, directions: &[u8]) {
+        for chunk in directions.chunks(CHUNK_SIZE) {
+            let (ups, downs) = count_directions(chunk);
+
+            // Santa can only reach the basement in this chunk if he's fewer floors above it than
+            // the chunk has steps down, so only follow the chunk step by step when he might
+            if self.first_basement_position.is_none() && self.floor - (downs as i32) < 0 {
+                self.find_basement(chunk);
+            }
+
+            self.floor += ups as i32 - downs as i32;
+            self.steps += ups as usize + downs as usize;
+        }
+    }
+
+    fn find_basement(&mut self, chunk: &[u8]) {
+        let mut floor = self.floor;
+
+        for (i, &b) in chunk.iter().filter(|&&b| is_direction_byte(b)).enumerate() {
+            floor += floor_change_byte(b) as i32;
+
+            if floor < 0 {
+                self.first_basement_position = Some(self.steps + i + 1);
+                return;
+            }
+        }
+    }
+}
+
+/// Counts the opening and closing parentheses in a chunk of at most [`CHUNK_SIZE`] bytes.
+fn count_directions(chunk: &[u8]) -> (u8, u8) {
+    chunk.iter().fold((0u8, 0u8), |(ups, downs), &b| {
+        (ups + (b == b'(') as u8, downs + (b == b')') as u8)
+    })
+}
+
+fn floor_change_byte(b: u8) -> i8 {
+    (b == b'(') as i8 - (b == b')') as i8
+}
+
+/// Returns the floor Santa ends up on. Bytes other than parentheses (including those of any
+/// multi-byte characters, which never contain ASCII bytes) don't change the floor.
+pub fn get_floor(directions: &str) -> i32 {
+    directions.as_bytes()
+        .chunks(CHUNK_SIZE)
+        .map(|chunk| {
+            let (ups, downs) = count_directions(chunk);
+            ups as i32 - downs as i32
+        })
+        .sum()
+}
+
+/// Returns the 1-based position of the direction that first takes Santa into the basement, or
+/// `None` if he never enters it. Only parentheses count towards the position.
+pub fn find_first_basement_position(directions: &str) -> Option<usize> {
+    let mut progress = Progress::default();
+
+    for chunk in directions.as_bytes().chunks(CHUNK_SIZE) {
+        progress.follow(chunk);
+
+        if progress.first_basement_position.is_some() {
+            break;
+        }
+    }
+
+    progress.first_basement_position
+}
+
+/// The floor Santa is on after each step of following a set of directions.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FloorTrace {
+    /// The floor after each step, starting with the ground floor before the first step
+    floors: Vec<i32>,
+}
+
+/// The highest or lowest floor Santa reaches, along with every step after which he's on that floor
+/// (where step 0 is the starting position on the ground floor).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Extreme {
+    pub floor: i32,
+    pub steps: Vec<usize>,
+}
+
+impl FloorTrace {
+    /// Traces the given directions, ignoring any characters other than parentheses.
+    pub fn new(directions: &str) -> Self {
+        let floors = std::iter::once(0)
+            .chain(directions.chars().filter(|&c| is_direction(c)).scan(0, |floor, c| {
+                *floor += floor_change(c);
+                Some(*floor)
+            }))
+            .collect();
+
+        FloorTrace { floors }
+    }
+
+    /// Returns the number of steps in the directions.
+    pub fn steps(&self) -> usize {
+        self.floors.len() - 1
+    }
+
+    /// Returns the floor after each step, starting with the ground floor before the first step.
+    pub fn floors(&self) -> &[i32] {
+        &self.floors
+    }
+
+    /// Returns the floor after the given step, or `None` if there aren't that many steps.
+    pub fn floor_after(&self, step: usize) -> Option<i32> {
+        self.floors.get(step).copied()
+    }
+
+    pub fn final_floor(&self) -> i32 {
+        *self.floors.last().unwrap()
+    }
+
+    pub fn highest(&self) -> Extreme {
+        self.extreme(*self.floors.iter().max().unwrap())
+    }
+
+    pub fn lowest(&self) -> Extreme {
+        self.extreme(*self.floors.iter().min().unwrap())
+    }
+
+    fn extreme(&self, floor: i32) -> Extreme {
+        let steps = self.floors.iter()
+            .enumerate()
+            .filter(|(_, &f)| f == floor)
+            .map(|(step, _)| step)
+            .collect();
+
+        Extreme { floor, steps }
+    }
+
+    /// Returns the first step after which Santa is on the given floor, or `None` if he never
+    /// reaches it.
+    pub fn first_reached(&self, floor: i32) -> Option<usize> {
+        self.floors.iter().position(|&f| f == floor)
+    }
+
+    /// Returns the first step after which Santa is on each floor he visits.
+    pub fn first_steps(&self) -> BTreeMap<i32, usize> {
+        let mut first_steps = BTreeMap::new();
+
+        for (step, &floor) in self.floors.iter().enumerate() {
+            first_steps.entry(floor).or_insert(step);
+        }
+
+        first_steps
+    }
+
+    /// Returns the number of steps after which Santa is on each floor he visits, including the
+    /// starting position on the ground floor.
+    pub fn steps_per_floor(&self) -> BTreeMap<i32, usize> {
+        let mut steps_per_floor = BTreeMap::new();
+
+        for &floor in &self.floors {
+            *steps_per_floor.entry(floor).or_insert(0) += 1;
+        }
+
+        steps_per_floor
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn scalar_get_floor(directions: &str) -> i32 {
+        directions.chars()
+            .map(floor_change)
+            .sum()
+    }
+
+    fn scalar_first_basement_position(directions: &str) -> Option<usize> {
+        let mut current_floor = 0;
+
+        for (i, c) in directions.chars().filter(|&c| is_direction(c)).enumerate() {
+            current_floor += floor_change(c);
+
+            if current_floor < 0 {
+                return Some(i + 1);
+            }
+        }
+
+        None
+    }
+
+    #[test]
+    fn test_get_floor() {
+        assert_eq!(0, get_floor("(())"));
+        assert_eq!(0, get_floor("()()"));
+        assert_eq!(3, get_floor("((("));
+        assert_eq!(3, get_floor("(()(()("));
+        assert_eq!(3, get_floor("))((((("));
+        assert_eq!(-1, get_floor("())"));
+        assert_eq!(-1, get_floor("))("));
+        assert_eq!(-3, get_floor(")))"));
+        assert_eq!(-3, get_floor(")())())"));
+    }
+
+    #[test]
+    fn test_find_first_basement_position() {
+        assert_eq!(Some(1), find_first_basement_position(")"));
+        assert_eq!(Some(5), find_first_basement_position("()())"));
+        assert_eq!(Some(5), find_first_basement_position("()\n())"));
+        assert_eq!(None, find_first_basement_position("(()"));
+    }
+
+    #[test]
+    fn test_chunk_boundaries() {
+        // The basement is reached on the first step of the second chunk, and then again well
+        // after a chunk that dips below its starting floor without reaching the basement
+        let directions = format!("{}{}", "()".repeat(CHUNK_SIZE / 2), ")");
+        assert_eq!(Some(CHUNK_SIZE + 1), find_first_basement_position(&directions));
+
+        let directions = format!("({}{}", ")(".repeat(CHUNK_SIZE), "))");
+        assert_eq!(Some(2 * CHUNK_SIZE + 3), find_first_basement_position(&directions));
+        assert_eq!(-1, get_floor(&directions));
+
+        let directions = "(→)".repeat(CHUNK_SIZE);
+        assert_eq!(0, get_floor(&directions));
+        assert_eq!(None, find_first_basement_position(&directions));
+    }
+
+    #[test]
+    fn test_progress() {
+        let mut progress = Progress::default();
+
+        progress.follow(b"(()x");
+        assert_eq!(Progress { floor: 1, steps: 3, first_basement_position: None }, progress);
+
+        progress.follow(b"))x)(");
+        assert_eq!(Progress { floor: -1, steps: 7, first_basement_position: Some(5) }, progress);
+
+        // Only the first visit to the basement counts
+        progress.follow(b"))");
+        assert_eq!(Progress { floor: -3, steps: 9, first_basement_position: Some(5) }, progress);
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("(()", Day01::parse("(()\n").unwrap());
+        assert_eq!(
+            "Illegal direction 'x' at line 1, column 3: \"x\"",
+            Day01::parse("((x)").unwrap_err().to_string()
+        );
+
+        let lenient = Day01::parse_lenient("(x)\n)").unwrap();
+        assert_eq!("())", lenient.input);
+        assert_eq!(
+            vec!["Illegal direction 'x' at line 1, column 2: \"x\"", "Illegal direction '\\n' at line 1, column 4: \"\\n\""],
+            lenient.skipped.iter().map(|error| error.to_string()).collect::<Vec<String>>()
+        );
+    }
+
+    #[test]
+    fn test_never_enters_basement() {
+        assert_eq!(Some(Answer::from(String::from("never enters basement"))), Day01::part_two(&String::from("(()")));
+
+        let streamed = Day01::solve_streaming(&mut "(()\n".as_bytes(), None, ParseMode::Strict).unwrap();
+        assert_eq!(Some(Answer::from(String::from("never enters basement"))), streamed.part_two);
+        assert!(Day01::solve_streaming(&mut "(x)".as_bytes(), None, ParseMode::Strict).is_err());
+
+        let streamed = Day01::solve_streaming(&mut "(x))".as_bytes(), None, ParseMode::Lenient).unwrap();
+        assert_eq!(Some(Answer::from(3usize)), streamed.part_two);
+        assert_eq!(1, streamed.skipped.len());
+    }
+
+    #[test]
+    fn test_floor_trace() {
+        let trace = FloorTrace::new("(()))))(");
+
+        assert_eq!(8, trace.steps());
+        assert_eq!(&[0, 1, 2, 1, 0, -1, -2, -3, -2], trace.floors());
+        assert_eq!(Some(-1), trace.floor_after(5));
+        assert_eq!(None, trace.floor_after(9));
+        assert_eq!(-2, trace.final_floor());
+
+        assert_eq!(Extreme { floor: 2, steps: vec![2] }, trace.highest());
+        assert_eq!(Extreme { floor: -3, steps: vec![7] }, trace.lowest());
+
+        assert_eq!(Some(0), trace.first_reached(0));
+        assert_eq!(Some(6), trace.first_reached(-2));
+        assert_eq!(None, trace.first_reached(3));
+
+        assert_eq!(BTreeMap::from([(-3, 7), (-2, 6), (-1, 5), (0, 0), (1, 1), (2, 2)]), trace.first_steps());
+        assert_eq!(BTreeMap::from([(-3, 1), (-2, 2), (-1, 1), (0, 2), (1, 2), (2, 1)]), trace.steps_per_floor());
+    }
+
+    proptest! {
+        #[test]
+        fn test_get_floor_counts_parentheses(directions in "[()]*") {
+            let ups = directions.chars().filter(|&c| c == '(').count() as i32;
+            let downs = directions.chars().filter(|&c| c == ')').count() as i32;
+
+            prop_assert_eq!(ups - downs, get_floor(&directions));
+        }
+
+        #[test]
+        fn test_first_basement_position_is_first_negative_floor(directions in "[()]*\\)") {
+            // Ending with enough closing parentheses guarantees that we reach the basement
+            let directions = format!("{}{}", directions, ")".repeat(directions.len()));
+            let position = find_first_basement_position(&directions).unwrap();
+
+            prop_assert_eq!(-1, get_floor(&directions[..position]));
+            prop_assert!((1..position).all(|i| get_floor(&directions[..i]) >= 0));
+        }
+
+        #[test]
+        fn test_fast_path_agrees_with_scalar_version(directions in "[()]{0,1000}", noise in "[()x→\n]{0,300}") {
+            for directions in [directions, noise] {
+                prop_assert_eq!(scalar_get_floor(&directions), get_floor(&directions));
+                prop_assert_eq!(scalar_first_basement_position(&directions), find_first_basement_position(&directions));
+            }
+        }
+
+        #[test]
+        fn test_streaming_agrees_with_scalar_version(directions in "[()]{0,1000}", noise in "[()x→\n]{0,300}", capacity in 1..300usize) {
+            let input = format!("{}{}", directions, noise);
+            let mut reader = std::io::BufReader::with_capacity(capacity, input.as_bytes());
+            let streamed = Day01::solve_streaming(&mut reader, None, ParseMode::Lenient).unwrap();
+
+            let lenient = Day01::parse_lenient(&input).unwrap();
+            prop_assert_eq!(Some(Answer::from(scalar_get_floor(&input))), streamed.part_one);
+            prop_assert_eq!(Some(basement_answer(scalar_first_basement_position(&input))), streamed.part_two);
+            prop_assert_eq!(
+                lenient.skipped.iter().map(|error| error.to_string()).collect::<Vec<String>>(),
+                streamed.skipped.iter().map(|error| error.to_string()).collect::<Vec<String>>()
+            );
+        }
+
+        #[test]
+        fn test_floor_trace_agrees_with_scalar_functions(directions in "[()]{0,200}") {
+            let trace = FloorTrace::new(&directions);
+
+            prop_assert_eq!(get_floor(&directions), trace.final_floor());
+            prop_assert_eq!(trace.steps() + 1, trace.steps_per_floor().values().sum::<usize>());
+
+            prop_assert_eq!(trace.first_reached(-1), find_first_basement_position(&directions));
+        }
+    }
+}
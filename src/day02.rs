@@ -0,0 +1,1074 @@
+use std::cell::Cell;
+use std::cmp::Reverse;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::f64::consts::PI;
+use std::io::{self, BufRead, Write};
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+use crate::{error, stream, Answer, Lenient, ParseMode, Report, Part, Solution, Streamed, Streaming, Value};
+
+/// The number of buckets into which the slack of each present is grouped when summarizing an order.
+const SLACK_BUCKETS: usize = 10;
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Order;
+
+    fn parse(input: &str) -> Result<Self::Input, Box<dyn Error>> {
+        let totals = Cell::new(Totals::default());
+        let (presents, _) = error::parse_numbered_lines(input, ParseMode::Strict, |line| parse_within_totals(line, &totals))?;
+
+        Ok(Order { presents, totals: totals.get() })
+    }
+
+    fn parse_lenient(input: &str) -> Result<Lenient<Self::Input>, Box<dyn Error>> {
+        let totals = Cell::new(Totals::default());
+        let (presents, skipped) = error::parse_numbered_lines(input, ParseMode::Lenient, |line| parse_within_totals(line, &totals))?;
+
+        Ok(Lenient::new(Order { presents, totals: totals.get() }, skipped))
+    }
+
+    fn part_one(order: &Self::Input) -> Answer {
+        order.totals().wrapping_paper.into()
+    }
+
+    fn part_two(order: &Self::Input) -> Option<Answer> {
+        Some(order.totals().ribbon.into())
+    }
+
+    fn reports(order: &Self::Input) -> Vec<Report> {
+        OrderSummary::new(order.presents().iter().map(|(line, present)| (*line, present))).reports()
+    }
+}
+
+impl Streaming for Day02 {
+    fn solve_streaming(input: &mut dyn BufRead, part: Option<Part>, mode: ParseMode) -> Result<Streamed, Box<dyn Error>> {
+        let totals = Cell::new(Totals::default());
+        let skipped = stream::parse_lines(input, mode, |line| parse_within_totals(line, &totals), |_| {})?;
+
+        Ok(Streamed {
+            part_one: Part::One.is_selected(part).then(|| totals.get().wrapping_paper.into()),
+            part_two: Part::Two.is_selected(part).then(|| totals.get().ribbon.into()),
+            skipped: error::boxed(skipped),
+        })
+    }
+}
+
+/// Parses a present and adds it to the given running totals, failing if the present would make
+/// either total overflow. The totals are left unchanged if parsing fails, so presents skipped in
+/// lenient mode don't count towards them.
+fn parse_within_totals(line: &str, totals: &Cell<Totals>) -> Result<Present, ParseError> {
+    let present = Present::from_str(line)?;
+    totals.set(totals.get().with(&present).map_err(|kind| ParseError::new(kind, line, line))?);
+
+    Ok(present)
+}
+
+/// An order of presents, each paired with the line on which it appears, along with its totals,
+/// which are checked for overflow as the order is built so they can't overflow when solving.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Order {
+    presents: Vec<(usize, Present)>,
+    totals: Totals,
+}
+
+impl Order {
+    /// Creates an order of the given presents and their lines, or returns an error if either total
+    /// doesn't fit in a `u64`.
+    pub fn new(presents: Vec<(usize, Present)>) -> Result<Self, ErrorKind> {
+        let totals = Totals::of(presents.iter().map(|(_, present)| present))?;
+        Ok(Order { presents, totals })
+    }
+
+    pub fn presents(&self) -> &[(usize, Present)] {
+        &self.presents
+    }
+
+    pub fn totals(&self) -> Totals {
+        self.totals
+    }
+}
+
+/// The total wrapping paper and ribbon needed for an order of presents.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct Totals {
+    pub wrapping_paper: u64,
+    pub ribbon: u64,
+}
+
+impl Totals {
+    /// Returns the totals for the given presents, or an error if either total doesn't fit in a
+    /// `u64`.
+    pub fn of<'a>(presents: impl IntoIterator<Item = &'a Present>) -> Result<Self, ErrorKind> {
+        presents.into_iter().try_fold(Totals::default(), |totals, present| totals.with(present))
+    }
+
+    /// Adds the given present to these totals, or returns an error if either total would overflow.
+    pub fn with(self, present: &impl Packaging) -> Result<Self, ErrorKind> {
+        let add = |total: u64, amount: u128| u64::try_from(amount).ok()
+            .and_then(|amount| total.checked_add(amount))
+            .ok_or(ErrorKind::TotalOverflow);
+
+        Ok(Totals {
+            wrapping_paper: add(self.wrapping_paper, present.required_wrapping_paper())?,
+            ribbon: add(self.ribbon, present.required_ribbon())?,
+        })
+    }
+}
+
+/// The paper and ribbon needed to wrap a present of a particular shape. Every shape needs enough
+/// paper to cover its surface plus some slack, and enough ribbon to wrap around it plus a bow.
+/// Quantities are wide enough that they can't overflow for any shape with `u32` dimensions.
+pub trait Packaging {
+    fn surface_area(&self) -> u128;
+
+    /// Returns the extra paper needed for the present, which is the area of its smallest face.
+    fn slack(&self) -> u128;
+
+    /// Returns the length of ribbon needed to wrap the present, which is the smallest perimeter
+    /// around it.
+    fn ribbon_wrap(&self) -> u128;
+
+    fn volume(&self) -> u128;
+
+    /// Returns the length of ribbon needed for the present's bow, which is equal to its volume.
+    fn bow(&self) -> u128 {
+        self.volume()
+    }
+
+    fn required_wrapping_paper(&self) -> u128 {
+        self.surface_area() + self.slack()
+    }
+
+    fn required_ribbon(&self) -> u128 {
+        self.ribbon_wrap() + self.bow()
+    }
+}
+
+/// A rectangular box, written as `LxWxH`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Cuboid {
+    pub dimensions: [u32; 3]
+}
+
+impl Packaging for Cuboid {
+    fn surface_area(&self) -> u128 {
+        let [l, w, h] = self.dimensions.map(u128::from);
+        2 * ((l * w) + (w * h) + (l * h))
+    }
+
+    fn slack(&self) -> u128 {
+        let [l, w, h] = self.dimensions.map(u128::from);
+        *[l * w, w * h, l * h].iter().min().unwrap()
+    }
+
+    fn ribbon_wrap(&self) -> u128 {
+        let [l, w, h] = self.dimensions.map(u128::from);
+        2 * *[l + w, w + h, l + h].iter().min().unwrap()
+    }
+
+    fn volume(&self) -> u128 {
+        let [l, w, h] = self.dimensions.map(u128::from);
+        l * w * h
+    }
+}
+
+/// A cube, written as `cube S`. Needs exactly as much paper and ribbon as a cuboid with equal sides.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Cube {
+    pub side: u32,
+}
+
+impl Cube {
+    fn as_cuboid(&self) -> Cuboid {
+        Cuboid { dimensions: [self.side; 3] }
+    }
+}
+
+impl Packaging for Cube {
+    fn surface_area(&self) -> u128 {
+        self.as_cuboid().surface_area()
+    }
+
+    fn slack(&self) -> u128 {
+        self.as_cuboid().slack()
+    }
+
+    fn ribbon_wrap(&self) -> u128 {
+        self.as_cuboid().ribbon_wrap()
+    }
+
+    fn volume(&self) -> u128 {
+        self.as_cuboid().volume()
+    }
+}
+
+/// A solid cylinder, written as `cyl RxH`. Since the elves can't cut fractions of a square foot of
+/// paper or a foot of ribbon, every measurement is rounded up.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Cylinder {
+    pub radius: u32,
+    pub height: u32,
+}
+
+impl Packaging for Cylinder {
+    fn surface_area(&self) -> u128 {
+        round_up(2.0 * end_area(self.radius) + side_area(self.radius, self.height))
+    }
+
+    fn slack(&self) -> u128 {
+        round_up(end_area(self.radius).min(side_area(self.radius, self.height)))
+    }
+
+    /// Returns the shorter of the ribbon needed to go around the cylinder's circumference and the
+    /// ribbon needed to go around it lengthwise, over both ends.
+    fn ribbon_wrap(&self) -> u128 {
+        round_up(circumference(self.radius).min(2.0 * (2.0 * self.radius as f64 + self.height as f64)))
+    }
+
+    fn volume(&self) -> u128 {
+        round_up(end_area(self.radius) * self.height as f64)
+    }
+}
+
+/// A hollow cylinder, like a napkin ring, written as `tube OxIxH` for its outer radius, inner
+/// radius, and height. Paper covers its inside, outside, and both ends, and ribbon goes either
+/// around its outside or through the hole and around its wall. Every measurement is rounded up.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Tube {
+    pub outer_radius: u32,
+    pub inner_radius: u32,
+    pub height: u32,
+}
+
+impl Tube {
+    fn end_area(&self) -> f64 {
+        end_area(self.outer_radius) - end_area(self.inner_radius)
+    }
+}
+
+impl Packaging for Tube {
+    fn surface_area(&self) -> u128 {
+        round_up(2.0 * self.end_area() +
+            side_area(self.outer_radius, self.height) +
+            side_area(self.inner_radius, self.height))
+    }
+
+    fn slack(&self) -> u128 {
+        round_up(self.end_area().min(side_area(self.inner_radius, self.height)))
+    }
+
+    fn ribbon_wrap(&self) -> u128 {
+        let wall = 2.0 * (self.outer_radius as f64 - self.inner_radius as f64 + self.height as f64);
+        round_up(circumference(self.outer_radius).min(wall))
+    }
+
+    fn volume(&self) -> u128 {
+        round_up(self.end_area() * self.height as f64)
+    }
+}
+
+fn end_area(radius: u32) -> f64 {
+    PI * radius as f64 * radius as f64
+}
+
+fn side_area(radius: u32, height: u32) -> f64 {
+    circumference(radius) * height as f64
+}
+
+fn circumference(radius: u32) -> f64 {
+    2.0 * PI * radius as f64
+}
+
+fn round_up(value: f64) -> u128 {
+    value.ceil() as u128
+}
+
+/// A present of any shape in an order; orders may mix shapes freely.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Present {
+    Cuboid(Cuboid),
+    Cube(Cube),
+    Cylinder(Cylinder),
+    Tube(Tube),
+}
+
+impl Present {
+    fn shape(&self) -> &dyn Packaging {
+        match self {
+            Present::Cuboid(cuboid) => cuboid,
+            Present::Cube(cube) => cube,
+            Present::Cylinder(cylinder) => cylinder,
+            Present::Tube(tube) => tube,
+        }
+    }
+}
+
+impl Packaging for Present {
+    fn surface_area(&self) -> u128 {
+        self.shape().surface_area()
+    }
+
+    fn slack(&self) -> u128 {
+        self.shape().slack()
+    }
+
+    fn ribbon_wrap(&self) -> u128 {
+        self.shape().ribbon_wrap()
+    }
+
+    fn volume(&self) -> u128 {
+        self.shape().volume()
+    }
+}
+
+impl FromStr for Present {
+    type Err = ParseError;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        match string.split_once(' ') {
+            None => {
+                let dimensions = parse_dimensions(string, string)?;
+                Ok(Present::Cuboid(Cuboid { dimensions }))
+            },
+            Some(("cube", dimensions)) => {
+                let [side] = parse_dimensions(string, dimensions)?;
+                Ok(Present::Cube(Cube { side }))
+            },
+            Some(("cyl", dimensions)) => {
+                let [radius, height] = parse_dimensions(string, dimensions)?;
+                Ok(Present::Cylinder(Cylinder { radius, height }))
+            },
+            Some(("tube", dimensions)) => {
+                let [outer_radius, inner_radius, height] = parse_dimensions(string, dimensions)?;
+
+                if inner_radius >= outer_radius {
+                    return Err(ParseError::new(ErrorKind::InnerRadiusTooLarge, string, dimensions));
+                }
+
+                Ok(Present::Tube(Tube { outer_radius, inner_radius, height }))
+            },
+            Some((shape, _)) => Err(ParseError::new(ErrorKind::UnknownShape, string, shape)),
+        }
+    }
+}
+
+fn parse_dimensions<const N: usize>(line: &str, dimensions: &str) -> Result<[u32; N], ParseError> {
+    dimensions.split('x')
+        .map(|component| component.parse::<u32>()
+            .map_err(|_| ParseError::new(ErrorKind::InvalidDimension, line, component)))
+        .collect::<Result<Vec<u32>, _>>()?
+        .try_into()
+        .map_err(|found: Vec<u32>| {
+            let kind = ErrorKind::WrongNumberOfDimensions { expected: N, found: found.len() };
+            ParseError::new(kind, line, dimensions)
+        })
+}
+
+impl Display for Present {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Present::Cuboid(Cuboid { dimensions }) =>
+                write!(f, "{}x{}x{}", dimensions[0], dimensions[1], dimensions[2]),
+            Present::Cube(Cube { side }) => write!(f, "cube {}", side),
+            Present::Cylinder(Cylinder { radius, height }) => write!(f, "cyl {}x{}", radius, height),
+            Present::Tube(Tube { outer_radius, inner_radius, height }) =>
+                write!(f, "tube {}x{}x{}", outer_radius, inner_radius, height),
+        }
+    }
+}
+
+/// The materials needed for a single present in an order, along with the line of the order on
+/// which the present appears.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PresentBreakdown {
+    pub line: usize,
+    pub present: Present,
+    pub surface_area: u128,
+    pub slack: u128,
+    pub paper: u128,
+    pub ribbon_wrap: u128,
+    pub bow: u128,
+}
+
+impl PresentBreakdown {
+    pub fn new(line: usize, present: &Present) -> Self {
+        PresentBreakdown {
+            line,
+            present: present.clone(),
+            surface_area: present.surface_area(),
+            slack: present.slack(),
+            paper: present.required_wrapping_paper(),
+            ribbon_wrap: present.ribbon_wrap(),
+            bow: present.bow(),
+        }
+    }
+
+    pub fn ribbon(&self) -> u128 {
+        self.ribbon_wrap + self.bow
+    }
+}
+
+/// A per-present breakdown of the wrapping paper and ribbon needed for an order of presents.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct OrderSummary {
+    pub presents: Vec<PresentBreakdown>,
+}
+
+impl OrderSummary {
+    /// Summarizes the given presents, each paired with the line on which it appears.
+    pub fn new<'a>(presents: impl IntoIterator<Item = (usize, &'a Present)>) -> Self {
+        OrderSummary {
+            presents: presents.into_iter()
+                .map(|(line, present)| PresentBreakdown::new(line, present))
+                .collect(),
+        }
+    }
+
+    /// Reads and summarizes an order, keeping track of the line on which each present appears.
+    /// Like [`Solution::parse`], fails on the first malformed line in strict mode and skips
+    /// malformed lines in lenient mode.
+    pub fn read(input: &mut dyn BufRead, mode: ParseMode) -> Result<Lenient<Self>, Box<dyn Error>> {
+        let mut presents = Vec::new();
+        let mut skipped = Vec::new();
+
+        stream::for_each_line(input, |line_number, line| -> Result<(), Box<dyn Error>> {
+            match Present::from_str(line) {
+                Ok(present) => presents.push(PresentBreakdown::new(line_number, &present)),
+                Err(error) => match mode {
+                    ParseMode::Strict => return Err(Box::new(error.at_line(line_number))),
+                    ParseMode::Lenient => skipped.push(error.at_line(line_number)),
+                },
+            }
+
+            Ok(())
+        })?;
+
+        Ok(Lenient::new(OrderSummary { presents }, skipped))
+    }
+
+    /// Returns the total paper for the order, or `None` if it doesn't fit in a `u128`.
+    pub fn total_paper(&self) -> Option<u128> {
+        self.presents.iter().try_fold(0u128, |total, present| total.checked_add(present.paper))
+    }
+
+    /// Returns the total ribbon for the order, or `None` if it doesn't fit in a `u128`.
+    pub fn total_ribbon(&self) -> Option<u128> {
+        self.presents.iter().try_fold(0u128, |total, present| total.checked_add(present.ribbon()))
+    }
+
+    /// Returns the present with the greatest volume, preferring the earliest in the order if more
+    /// than one present has the greatest volume.
+    pub fn largest(&self) -> Option<&PresentBreakdown> {
+        self.presents.iter()
+            .reduce(|largest, present| if present.bow > largest.bow { present } else { largest })
+    }
+
+    pub fn mean_paper(&self) -> Option<f64> {
+        if self.presents.is_empty() {
+            None
+        } else {
+            let total_paper: f64 = self.presents.iter().map(|present| present.paper as f64).sum();
+            Some(total_paper / self.presents.len() as f64)
+        }
+    }
+
+    /// Groups presents by slack into the given number of equally-sized ranges, starting from zero
+    /// and covering the greatest slack of any present, and returns the number of presents in each
+    /// range. Fewer ranges are returned if the greatest slack is less than the number of ranges.
+    pub fn slack_distribution(&self, buckets: usize) -> Vec<(RangeInclusive<u128>, usize)> {
+        let Some(max_slack) = self.presents.iter().map(|present| present.slack).max() else {
+            return Vec::new();
+        };
+
+        let width = max_slack / buckets.max(1) as u128 + 1;
+        let mut counts = vec![0; (max_slack / width) as usize + 1];
+
+        for present in &self.presents {
+            counts[(present.slack / width) as usize] += 1;
+        }
+
+        counts.into_iter()
+            .enumerate()
+            .map(|(i, count)| (i as u128 * width..=(i as u128 + 1) * width - 1, count))
+            .collect()
+    }
+
+    pub fn reports(&self) -> Vec<Report> {
+        let mut totals = vec![
+            (String::from("presents"), self.presents.len().into()),
+            (String::from("total paper"), self.total_paper().map_or_else(|| Value::from("overflow"), Value::from)),
+            (String::from("total ribbon"), self.total_ribbon().map_or_else(|| Value::from("overflow"), Value::from)),
+        ];
+
+        if let Some(largest) = self.largest() {
+            totals.push((String::from("largest present"), largest.present.to_string().into()));
+            totals.push((String::from("largest present volume"), largest.bow.into()));
+        }
+
+        if let Some(mean_paper) = self.mean_paper() {
+            totals.push((String::from("mean paper per present"), format!("{:.2}", mean_paper).into()));
+        }
+
+        vec![
+            Report { title: "Order totals", entries: totals },
+            Report {
+                title: "Presents by slack",
+                entries: self.slack_distribution(SLACK_BUCKETS).into_iter()
+                    .map(|(range, count)| (format!("slack {}-{}", range.start(), range.end()), count.into()))
+                    .collect(),
+            },
+        ]
+    }
+
+    pub fn write_csv(&self, out: &mut dyn Write) -> io::Result<()> {
+        writeln!(out, "line,dimensions,surface_area,slack,paper,ribbon_wrap,bow")?;
+
+        for row in self.rows() {
+            writeln!(out, "{}", row.join(","))?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes the breakdown as a table with aligned columns, followed by the order's aggregate
+    /// statistics.
+    pub fn write_table(&self, out: &mut dyn Write) -> io::Result<()> {
+        const HEADERS: [&str; 7] = ["Line", "Dimensions", "Surface area", "Slack", "Paper", "Ribbon wrap", "Bow"];
+
+        let rows = self.rows();
+        let mut widths = HEADERS.map(str::len);
+
+        for row in &rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.len());
+            }
+        }
+
+        let format_row = |cells: &[String]| cells.iter()
+            .zip(widths)
+            .map(|(cell, width)| format!("{:>width$}", cell, width = width))
+            .collect::<Vec<String>>()
+            .join("  ");
+
+        writeln!(out, "{}", format_row(&HEADERS.map(String::from)))?;
+
+        for row in &rows {
+            writeln!(out, "{}", format_row(row))?;
+        }
+
+        for report in self.reports() {
+            writeln!(out)?;
+            writeln!(out, "{}:", report.title)?;
+
+            for (label, value) in &report.entries {
+                writeln!(out, "    {}: {}", label, value)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn rows(&self) -> Vec<[String; 7]> {
+        self.presents.iter()
+            .map(|present| [
+                present.line.to_string(),
+                present.present.to_string(),
+                present.surface_area.to_string(),
+                present.slack.to_string(),
+                present.paper.to_string(),
+                present.ribbon_wrap.to_string(),
+                present.bow.to_string(),
+            ])
+            .collect()
+    }
+}
+
+/// A roll of wrapping paper, written as `WxL` for its width and length in feet.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct PaperRoll {
+    pub width: u32,
+    pub length: u32,
+}
+
+impl PaperRoll {
+    /// Returns the area of paper on the roll, in square feet. Cuts are planned by area alone, as
+    /// if the elves could piece together each present's paper from any shape of offcut.
+    pub fn area(&self) -> u128 {
+        self.width as u128 * self.length as u128
+    }
+}
+
+impl FromStr for PaperRoll {
+    type Err = ParseError;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let [width, length] = parse_dimensions(string, string)?;
+        Ok(PaperRoll { width, length })
+    }
+}
+
+impl Display for PaperRoll {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}x{}", self.width, self.length)
+    }
+}
+
+/// The number of rolls or spools beyond which a plan is abandoned rather than listing every one.
+const MAX_BINS: usize = 10_000_000;
+
+/// A plan for cutting each present's share of some material from identical rolls or spools, each
+/// of which holds the given capacity.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CuttingPlan {
+    pub capacity: u128,
+    pub bins: Vec<Bin>,
+}
+
+/// A single roll or spool, and the cuts made from it.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Bin {
+    pub cuts: Vec<Cut>,
+}
+
+/// An amount of material cut for the present on the given line of the order.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Cut {
+    pub line: usize,
+    pub amount: u128,
+}
+
+impl CuttingPlan {
+    /// Plans cuts for the given amounts, each paired with the line of the present that needs it,
+    /// using the first-fit decreasing heuristic: amounts are cut largest first from the first bin
+    /// with enough left, and a new bin is started when none has. Amounts bigger than a whole bin
+    /// first take as many whole bins as they can fill, and the rest is cut like any other amount.
+    pub fn first_fit_decreasing(
+        capacity: u128,
+        amounts: impl IntoIterator<Item = (usize, u128)>,
+    ) -> Result<Self, PlanError> {
+        let mut bins = Vec::new();
+        let mut remainders = Vec::new();
+
+        for (line, amount) in amounts.into_iter().filter(|&(_, amount)| amount > 0) {
+            if capacity == 0 {
+                return Err(PlanError::EmptyStock);
+            }
+
+            let whole_bins = amount / capacity;
+
+            if whole_bins > (MAX_BINS - bins.len()) as u128 {
+                return Err(PlanError::TooManyBins { line });
+            }
+
+            bins.extend((0..whole_bins).map(|_| Bin { cuts: vec![Cut { line, amount: capacity }] }));
+
+            if amount % capacity > 0 {
+                remainders.push(Cut { line, amount: amount % capacity });
+            }
+        }
+
+        // The sort is stable, so equal amounts are cut in the order in which they appear
+        remainders.sort_by_key(|cut| Reverse(cut.amount));
+
+        // Bins filled by a single amount have nothing left, so only track the rest
+        let first_partial_bin = bins.len();
+        let mut remaining: Vec<u128> = Vec::new();
+
+        for cut in remainders {
+            match remaining.iter().position(|&left| left >= cut.amount) {
+                Some(i) => {
+                    remaining[i] -= cut.amount;
+                    bins[first_partial_bin + i].cuts.push(cut);
+                },
+                None if bins.len() == MAX_BINS => return Err(PlanError::TooManyBins { line: cut.line }),
+                None => {
+                    remaining.push(capacity - cut.amount);
+                    bins.push(Bin { cuts: vec![cut] });
+                },
+            }
+        }
+
+        Ok(CuttingPlan { capacity, bins })
+    }
+
+    pub fn used(&self) -> u128 {
+        self.bins.iter().map(Bin::used).sum()
+    }
+
+    /// Returns the material left over on every bin once all cuts have been made.
+    pub fn waste(&self) -> u128 {
+        self.bins.len() as u128 * self.capacity - self.used()
+    }
+}
+
+impl Bin {
+    pub fn used(&self) -> u128 {
+        self.cuts.iter().map(|cut| cut.amount).sum()
+    }
+}
+
+/// The paper rolls and ribbon spools needed to wrap every present in an order.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InventoryPlan {
+    pub roll: PaperRoll,
+    pub spool_length: u32,
+    pub paper: CuttingPlan,
+    pub ribbon: CuttingPlan,
+}
+
+impl InventoryPlan {
+    pub fn new(order: &OrderSummary, roll: PaperRoll, spool_length: u32) -> Result<Self, PlanError> {
+        Ok(InventoryPlan {
+            roll,
+            spool_length,
+            paper: CuttingPlan::first_fit_decreasing(roll.area(),
+                order.presents.iter().map(|present| (present.line, present.paper)))?,
+            ribbon: CuttingPlan::first_fit_decreasing(spool_length as u128,
+                order.presents.iter().map(|present| (present.line, present.ribbon())))?,
+        })
+    }
+
+    /// Writes a summary of each plan, followed by the cuts to make from each roll and spool.
+    pub fn write(&self, out: &mut dyn Write) -> io::Result<()> {
+        let plans = [
+            ("Paper", "Roll", format!("rolls of {} ({} sq ft each)", self.roll, self.roll.area()), &self.paper),
+            ("Ribbon", "Spool", format!("spools of {} ft", self.spool_length), &self.ribbon),
+        ];
+
+        for (i, (material, bin_name, stock, plan)) in plans.into_iter().enumerate() {
+            if i > 0 {
+                writeln!(out)?;
+            }
+
+            let total = plan.bins.len() as u128 * plan.capacity;
+            let waste_percent = if total == 0 { 0.0 } else { plan.waste() as f64 / total as f64 * 100.0 };
+
+            writeln!(out, "{}: {} {}; {} used, {} wasted ({:.1}%)",
+                     material, plan.bins.len(), stock, plan.used(), plan.waste(), waste_percent)?;
+
+            for (number, bin) in plan.bins.iter().enumerate() {
+                let cuts: Vec<String> = bin.cuts.iter()
+                    .map(|cut| format!("line {} ({})", cut.line, cut.amount))
+                    .collect();
+
+                writeln!(out, "    {} {} ({} wasted): {}", bin_name, number + 1, plan.capacity - bin.used(), cuts.join(", "))?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PlanError {
+    EmptyStock,
+    TooManyBins { line: usize },
+}
+
+impl Display for PlanError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PlanError::EmptyStock => write!(f, "Rolls and spools must hold some material"),
+            PlanError::TooManyBins { line } =>
+                write!(f, "The present on line {} would need more than {} rolls or spools", line, MAX_BINS),
+        }
+    }
+}
+
+impl Error for PlanError {}
+
+pub type ParseError = error::ParseError<ErrorKind>;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ErrorKind {
+    InvalidDimension,
+    WrongNumberOfDimensions { expected: usize, found: usize },
+    UnknownShape,
+    InnerRadiusTooLarge,
+    TotalOverflow,
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ErrorKind::InvalidDimension => write!(f, "Could not parse dimension"),
+            ErrorKind::WrongNumberOfDimensions { expected, found } =>
+                write!(f, "Expected {} dimensions, but found {}", expected, found),
+            ErrorKind::UnknownShape => write!(f, "Unknown shape"),
+            ErrorKind::InnerRadiusTooLarge => write!(f, "Inner radius must be smaller than outer radius"),
+            ErrorKind::TotalOverflow => write!(f, "Total paper or ribbon for the order would overflow"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use indoc::indoc;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_present_from_string() {
+        assert_eq!(Present::Cuboid(Cuboid { dimensions: [1, 12, 3] }), Present::from_str("1x12x3").unwrap());
+        assert_eq!(Present::Cube(Cube { side: 4 }), Present::from_str("cube 4").unwrap());
+        assert_eq!(Present::Cylinder(Cylinder { radius: 3, height: 10 }), Present::from_str("cyl 3x10").unwrap());
+        assert_eq!(
+            Present::Tube(Tube { outer_radius: 3, inner_radius: 2, height: 1 }),
+            Present::from_str("tube 3x2x1").unwrap()
+        );
+
+        let error = Present::from_str("1x1z2x3").unwrap_err();
+        assert_eq!(ErrorKind::InvalidDimension, error.kind);
+        assert_eq!(3, error.column);
+        assert_eq!("1z2", error.text);
+
+        let error = Present::from_str("1x2").unwrap_err();
+        assert_eq!(ErrorKind::WrongNumberOfDimensions { expected: 3, found: 2 }, error.kind);
+        assert_eq!(1, error.column);
+
+        let error = Present::from_str("cyl 1x2x3").unwrap_err();
+        assert_eq!(ErrorKind::WrongNumberOfDimensions { expected: 2, found: 3 }, error.kind);
+        assert_eq!(5, error.column);
+
+        let error = Present::from_str("tube 2x2x1").unwrap_err();
+        assert_eq!(ErrorKind::InnerRadiusTooLarge, error.kind);
+        assert_eq!("2x2x1", error.text);
+
+        let error = Present::from_str("sphere 3").unwrap_err();
+        assert_eq!(ErrorKind::UnknownShape, error.kind);
+        assert_eq!("sphere", error.text);
+    }
+
+    #[test]
+    fn test_required_wrapping_paper() {
+        assert_eq!(58, Cuboid { dimensions: [2, 3, 4] }.required_wrapping_paper());
+        assert_eq!(43, Cuboid { dimensions: [1, 1, 10] }.required_wrapping_paper());
+        assert_eq!(28, Cube { side: 2 }.required_wrapping_paper());
+        assert_eq!(23, Cylinder { radius: 1, height: 2 }.required_wrapping_paper());
+        assert_eq!(86, Tube { outer_radius: 2, inner_radius: 1, height: 3 }.required_wrapping_paper());
+    }
+
+    #[test]
+    fn test_required_ribbon() {
+        assert_eq!(34, Cuboid { dimensions: [2, 3, 4] }.required_ribbon());
+        assert_eq!(14, Cuboid { dimensions: [1, 1, 10] }.required_ribbon());
+        assert_eq!(16, Cube { side: 2 }.required_ribbon());
+        assert_eq!(14, Cylinder { radius: 1, height: 2 }.required_ribbon());
+        assert_eq!(37, Tube { outer_radius: 2, inner_radius: 1, height: 3 }.required_ribbon());
+    }
+
+    #[test]
+    fn test_mixed_shapes() {
+        let presents = Day02::parse("2x3x4\ncube 2\ncyl 1x2\ntube 2x1x3\n").unwrap();
+
+        assert_eq!(Answer::from(195u32), Day02::part_one(&presents));
+        assert_eq!(Some(Answer::from(101u32)), Day02::part_two(&presents));
+    }
+
+    #[test]
+    fn test_large_presents() {
+        let presents = Day02::parse("70000x70000x70000\n").unwrap();
+        assert_eq!(Answer::from(34_300_000_000u64), Day02::part_one(&presents));
+        assert_eq!(Some(Answer::from(343_000_000_280_000u64)), Day02::part_two(&presents));
+
+        let huge = Present::from_str("4294967295x4294967295x4294967295").unwrap();
+        assert_eq!(79_228_162_458_924_105_385_300_197_375, huge.volume());
+
+        let error = Day02::parse("2x3x4\n4294967295x4294967295x4294967295\n").unwrap_err();
+        assert_eq!(
+            "Total paper or ribbon for the order would overflow at line 2, column 1: \"4294967295x4294967295x4294967295\"",
+            error.to_string()
+        );
+
+        let lenient = Day02::parse_lenient("2x3x4\n4294967295x4294967295x4294967295\n1x1x10\n").unwrap();
+        assert_eq!(1, lenient.skipped.len());
+        assert_eq!(Answer::from(101u32), Day02::part_one(&lenient.input));
+        assert_eq!(vec![1, 3], lenient.input.presents().iter().map(|(line, _)| *line).collect::<Vec<usize>>());
+
+        // Presents that fit on their own can still overflow the totals together
+        let many = "100000000x100000000x1\n".repeat(1000);
+        assert!(Day02::parse(&many).is_err());
+        assert!(Day02::solve_streaming(&mut many.as_bytes(), None, ParseMode::Strict).is_err());
+
+        let lenient = Day02::parse_lenient(&many).unwrap();
+        assert_eq!(922, lenient.input.presents().len());
+        assert_eq!(Ok(lenient.input.totals()), Totals::of(lenient.input.presents().iter().map(|(_, present)| present)));
+
+        let presents = vec![(1, Present::from_str("100000000x100000000x1").unwrap()); 1000];
+        assert_eq!(Err(ErrorKind::TotalOverflow), Order::new(presents));
+    }
+
+    #[test]
+    fn test_present_components() {
+        let present = Present::Cuboid(Cuboid { dimensions: [2, 3, 4] });
+
+        assert_eq!(52, present.surface_area());
+        assert_eq!(6, present.slack());
+        assert_eq!(10, present.ribbon_wrap());
+        assert_eq!(24, present.bow());
+        assert_eq!("2x3x4", present.to_string());
+    }
+
+    #[test]
+    fn test_order_summary() {
+        let order = OrderSummary::read(&mut "2x3x4\nbad\n1x1x10\n4x3x2\n".as_bytes(), ParseMode::Lenient).unwrap();
+        assert_eq!(1, order.skipped.len());
+
+        let order = order.input;
+        assert_eq!(vec![1, 3, 4], order.presents.iter().map(|present| present.line).collect::<Vec<usize>>());
+        assert_eq!(Some(159), order.total_paper());
+        assert_eq!(Some(82), order.total_ribbon());
+        assert_eq!(Some(1), order.largest().map(|present| present.line));
+        assert_eq!(Some(53.0), order.mean_paper());
+        assert_eq!(vec![(0..=3, 1), (4..=7, 2)], order.slack_distribution(2));
+
+        assert!(OrderSummary::read(&mut "2x3x4\nbad\n".as_bytes(), ParseMode::Strict).is_err());
+        assert!(OrderSummary::default().slack_distribution(SLACK_BUCKETS).is_empty());
+
+        // Totals that don't fit are reported rather than overflowing
+        let mut huge = PresentBreakdown::new(1, &Present::from_str("2x3x4").unwrap());
+        huge.paper = u128::MAX;
+
+        let order = OrderSummary { presents: vec![huge.clone(), huge] };
+        assert_eq!(None, order.total_paper());
+        assert_eq!(Some(68), order.total_ribbon());
+        assert_eq!(Value::from("overflow"), order.reports()[0].entries[1].1);
+    }
+
+    #[test]
+    fn test_write_order_summary() {
+        let presents = [Present::from_str("2x3x4").unwrap(), Present::from_str("1x1x10").unwrap()];
+        let order = OrderSummary::new([(1, &presents[0]), (2, &presents[1])]);
+
+        let mut csv = Vec::new();
+        order.write_csv(&mut csv).unwrap();
+        assert_eq!(
+            "line,dimensions,surface_area,slack,paper,ribbon_wrap,bow\n1,2x3x4,52,6,58,10,24\n2,1x1x10,42,1,43,4,10\n",
+            String::from_utf8(csv).unwrap()
+        );
+
+        let mut table = Vec::new();
+        order.write_table(&mut table).unwrap();
+        let table = String::from_utf8(table).unwrap();
+        let mut lines = table.lines();
+        assert_eq!(Some("Line  Dimensions  Surface area  Slack  Paper  Ribbon wrap  Bow"), lines.next());
+        assert_eq!(Some("   1       2x3x4            52      6     58           10   24"), lines.next());
+        assert!(table.contains("    mean paper per present: 50.50\n"));
+    }
+
+    fn present() -> impl Strategy<Value = Present> {
+        prop_oneof![
+            prop::array::uniform3(0..1000u32).prop_map(|dimensions| Present::Cuboid(Cuboid { dimensions })),
+            (0..1000u32).prop_map(|side| Present::Cube(Cube { side })),
+            (0..1000u32, 0..1000u32).prop_map(|(radius, height)| Present::Cylinder(Cylinder { radius, height })),
+            (1..1000u32, 0..1000u32).prop_flat_map(|(outer_radius, height)| (0..outer_radius)
+                .prop_map(move |inner_radius| Present::Tube(Tube { outer_radius, inner_radius, height }))),
+        ]
+    }
+
+    #[test]
+    fn test_first_fit_decreasing() {
+        let plan = CuttingPlan::first_fit_decreasing(10, [(1, 4), (2, 7), (3, 0), (4, 23), (5, 6), (6, 3)]).unwrap();
+
+        let cuts: Vec<Vec<(usize, u128)>> = plan.bins.iter()
+            .map(|bin| bin.cuts.iter().map(|cut| (cut.line, cut.amount)).collect())
+            .collect();
+
+        assert_eq!(vec![
+            vec![(4, 10)],
+            vec![(4, 10)],
+            vec![(2, 7), (4, 3)],
+            vec![(5, 6), (1, 4)],
+            vec![(6, 3)],
+        ], cuts);
+
+        assert_eq!(43, plan.used());
+        assert_eq!(7, plan.waste());
+
+        assert_eq!(Err(PlanError::EmptyStock), CuttingPlan::first_fit_decreasing(0, [(1, 1)]));
+        assert_eq!(Err(PlanError::TooManyBins { line: 2 }), CuttingPlan::first_fit_decreasing(1, [(1, 1), (2, u128::MAX)]));
+    }
+
+    #[test]
+    fn test_inventory_plan() {
+        let order = OrderSummary::read(&mut "2x3x4\n1x1x10\n".as_bytes(), ParseMode::Strict).unwrap().input;
+        let plan = InventoryPlan::new(&order, PaperRoll::from_str("5x10").unwrap(), 20).unwrap();
+
+        assert_eq!(3, plan.paper.bins.len());
+        assert_eq!(3, plan.ribbon.bins.len());
+
+        let mut out = Vec::new();
+        plan.write(&mut out).unwrap();
+
+        assert_eq!(indoc! {"
+            Paper: 3 rolls of 5x10 (50 sq ft each); 101 used, 49 wasted (32.7%)
+                Roll 1 (0 wasted): line 1 (50)
+                Roll 2 (7 wasted): line 2 (43)
+                Roll 3 (42 wasted): line 1 (8)
+
+            Ribbon: 3 spools of 20 ft; 48 used, 12 wasted (20.0%)
+                Spool 1 (0 wasted): line 1 (20)
+                Spool 2 (6 wasted): line 1 (14)
+                Spool 3 (6 wasted): line 2 (14)
+        "}, String::from_utf8(out).unwrap());
+    }
+
+    proptest! {
+        #[test]
+        fn test_first_fit_decreasing_cuts_every_amount(capacity in 1..100u128, amounts in prop::collection::vec(0..500u128, 0..50)) {
+            let plan = CuttingPlan::first_fit_decreasing(capacity, amounts.iter().copied().enumerate()).unwrap();
+
+            prop_assert_eq!(amounts.iter().sum::<u128>(), plan.used());
+            prop_assert!(plan.bins.iter().all(|bin| bin.used() <= capacity));
+
+            for (line, &amount) in amounts.iter().enumerate() {
+                let cut: u128 = plan.bins.iter()
+                    .flat_map(|bin| &bin.cuts)
+                    .filter(|cut| cut.line == line)
+                    .map(|cut| cut.amount)
+                    .sum();
+
+                prop_assert_eq!(amount, cut);
+            }
+        }
+
+        #[test]
+        fn test_present_from_string_round_trip(dimensions in prop::array::uniform3(0..1000u32)) {
+            let string = format!("{}x{}x{}", dimensions[0], dimensions[1], dimensions[2]);
+
+            prop_assert_eq!(Present::Cuboid(Cuboid { dimensions }), Present::from_str(&string).unwrap());
+            prop_assert_eq!(string, Present::Cuboid(Cuboid { dimensions }).to_string());
+        }
+
+        #[test]
+        fn test_any_shape_round_trip(present in present()) {
+            prop_assert_eq!(&present, &Present::from_str(&present.to_string()).unwrap());
+        }
+
+        #[test]
+        fn test_cube_matches_cuboid(side in 0..1000u32) {
+            let cuboid = Cuboid { dimensions: [side; 3] };
+
+            prop_assert_eq!(cuboid.required_wrapping_paper(), Cube { side }.required_wrapping_paper());
+            prop_assert_eq!(cuboid.required_ribbon(), Cube { side }.required_ribbon());
+        }
+
+        #[test]
+        fn test_requirements_ignore_dimension_order(dimensions in prop::array::uniform3(1..1000u32)) {
+            let present = Cuboid { dimensions };
+            let mut sorted = dimensions;
+            sorted.sort();
+
+            prop_assert_eq!(present.required_wrapping_paper(), Cuboid { dimensions: sorted }.required_wrapping_paper());
+            prop_assert_eq!(present.required_ribbon(), Cuboid { dimensions: sorted }.required_ribbon());
+        }
+
+        #[test]
+        fn test_parse_does_not_panic(input in "\\PC*") {
+            let _ = Day02::parse(&input);
+        }
+    }
+}
@@ -0,0 +1,1007 @@
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io::{self, BufRead, Write};
+use std::num::NonZeroUsize;
+use std::ops::AddAssign;
+use std::str::FromStr;
+use crate::{error, stream, Answer, Lenient, ParseMode, Report, Part, Solution, Streamed, Streaming};
+use crate::day03::Move::{East, North, South, West};
+use crate::grid::{Point, Rect, SparseGrid};
+
+pub use crate::grid::Point as Position;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Vec<Move>;
+
+    fn parse(input: &str) -> Result<Self::Input, Box<dyn Error>> {
+        Ok(parse_moves(input, ParseMode::Strict)?.0)
+    }
+
+    fn parse_lenient(input: &str) -> Result<Lenient<Self::Input>, Box<dyn Error>> {
+        let (moves, skipped) = parse_moves(input, ParseMode::Lenient)?;
+        Ok(Lenient::new(moves, skipped))
+    }
+
+    fn part_one(moves: &Self::Input) -> Answer {
+        distinct_houses_visited(moves, 1).into()
+    }
+
+    fn part_two(moves: &Self::Input) -> Option<Answer> {
+        Some(distinct_houses_visited(moves, 2).into())
+    }
+
+    fn reports(moves: &Self::Input) -> Vec<Report> {
+        Heatmap::new(moves, NonZeroUsize::MIN).reports()
+    }
+}
+
+impl Streaming for Day03 {
+    fn solve_streaming(input: &mut dyn BufRead, part: Option<Part>, mode: ParseMode) -> Result<Streamed, Box<dyn Error>> {
+        let mut santa = Part::One.is_selected(part).then(|| Delivery::new(1));
+        let mut santa_and_robot = Part::Two.is_selected(part).then(|| Delivery::new(2));
+
+        let mut skipped = Vec::new();
+
+        let mut reject = |offset: usize, c: char| -> Result<(), ParseError> {
+            let error = ParseError { kind: ErrorKind::IllegalDirection(c), line: 1, column: offset + 1, text: c.to_string() };
+
+            match mode {
+                ParseMode::Strict => return Err(error),
+                ParseMode::Lenient => skipped.push(error),
+            }
+
+            Ok(())
+        };
+
+        stream::for_each_char_trim_end(input, |offset, c| -> Result<(), Box<dyn Error>> {
+            match Move::try_from(c) {
+                Ok(mov) => santa.iter_mut().chain(santa_and_robot.iter_mut()).for_each(|delivery| delivery.deliver(&mov)),
+                Err(_) => reject(offset, c)?,
+            }
+
+            Ok(())
+        })?;
+
+        Ok(Streamed {
+            part_one: santa.map(|santa| (santa.presents.len() as u32).into()),
+            part_two: santa_and_robot.map(|santa_and_robot| (santa_and_robot.presents.len() as u32).into()),
+            skipped: error::boxed(skipped),
+        })
+    }
+}
+
+/// Parses moves in any direction set, ignoring trailing whitespace. Moves are all on a single line,
+/// so lenient mode skips individual illegal characters instead of lines.
+pub fn parse_moves<D: Direction>(input: &str, mode: ParseMode) -> Result<(Vec<D>, Vec<ParseError>), ParseError> {
+    let input = input.trim_end();
+
+    let mut moves = Vec::new();
+    let mut skipped = Vec::new();
+
+    for (i, c) in input.char_indices() {
+        match D::try_from(c) {
+            Ok(mov) => moves.push(mov),
+            Err(kind) => {
+                let error = ParseError::new(kind, input, &input[i..i + c.len_utf8()]);
+
+                match mode {
+                    ParseMode::Strict => return Err(error),
+                    ParseMode::Lenient => skipped.push(error),
+                }
+            },
+        }
+    }
+
+    Ok((moves, skipped))
+}
+
+/// A set of directions in which actors can move between houses, each written as a single
+/// character. Every direction set shares [`Position`] coordinates, so the same functions can
+/// deliver presents on any of them.
+pub trait Direction: Copy + TryFrom<char, Error = ErrorKind> {
+    /// Returns the change in position from moving one house in this direction.
+    fn offset(self) -> Point;
+}
+
+/// A move to one of the four orthogonally adjacent houses, written as `^`, `v`, `>`, or `<`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Move {
+    North,
+    South,
+    East,
+    West,
+}
+
+impl TryFrom<char> for Move {
+    type Error = ErrorKind;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '^' => Ok(North),
+            'v' => Ok(South),
+            '>' => Ok(East),
+            '<' => Ok(West),
+            _ => Err(ErrorKind::IllegalDirection(c))
+        }
+    }
+}
+
+pub type ParseError = error::ParseError<ErrorKind>;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ErrorKind {
+    IllegalDirection(char),
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ErrorKind::IllegalDirection(c) => write!(f, "Illegal direction {:?}", c),
+        }
+    }
+}
+
+impl Direction for Move {
+    fn offset(self) -> Point {
+        match self {
+            North => Point(0, 1),
+            South => Point(0, -1),
+            East => Point(1, 0),
+            West => Point(-1, 0),
+        }
+    }
+}
+
+/// A move to one of the eight orthogonally or diagonally adjacent houses. Orthogonal moves are
+/// written as they are for [`Move`], so any four-way route is also an eight-way route, and
+/// diagonal moves are written as the corner keys of a numeric keypad: `7` (north-west), `9`
+/// (north-east), `1` (south-west), and `3` (south-east).
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum CompassMove {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl TryFrom<char> for CompassMove {
+    type Error = ErrorKind;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '^' => Ok(CompassMove::North),
+            '9' => Ok(CompassMove::NorthEast),
+            '>' => Ok(CompassMove::East),
+            '3' => Ok(CompassMove::SouthEast),
+            'v' => Ok(CompassMove::South),
+            '1' => Ok(CompassMove::SouthWest),
+            '<' => Ok(CompassMove::West),
+            '7' => Ok(CompassMove::NorthWest),
+            _ => Err(ErrorKind::IllegalDirection(c))
+        }
+    }
+}
+
+impl Direction for CompassMove {
+    fn offset(self) -> Point {
+        match self {
+            CompassMove::North => Point(0, 1),
+            CompassMove::NorthEast => Point(1, 1),
+            CompassMove::East => Point(1, 0),
+            CompassMove::SouthEast => Point(1, -1),
+            CompassMove::South => Point(0, -1),
+            CompassMove::SouthWest => Point(-1, -1),
+            CompassMove::West => Point(-1, 0),
+            CompassMove::NorthWest => Point(-1, 1),
+        }
+    }
+}
+
+/// A move to one of the six adjacent houses on a grid of hexagonal blocks with pointy tops,
+/// written as the keys around `s` on a QWERTY keyboard: `w` (north-west), `e` (north-east), `d`
+/// (east), `x` (south-east), `z` (south-west), and `a` (west).
+///
+/// Positions are axial coordinates: the first coordinate increases to the east, and the second
+/// increases to the north-east, so moving north-east and then south-east is the same as moving
+/// east.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum HexMove {
+    NorthWest,
+    NorthEast,
+    East,
+    SouthEast,
+    SouthWest,
+    West,
+}
+
+impl TryFrom<char> for HexMove {
+    type Error = ErrorKind;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'w' => Ok(HexMove::NorthWest),
+            'e' => Ok(HexMove::NorthEast),
+            'd' => Ok(HexMove::East),
+            'x' => Ok(HexMove::SouthEast),
+            'z' => Ok(HexMove::SouthWest),
+            'a' => Ok(HexMove::West),
+            _ => Err(ErrorKind::IllegalDirection(c))
+        }
+    }
+}
+
+impl Direction for HexMove {
+    fn offset(self) -> Point {
+        match self {
+            HexMove::NorthWest => Point(-1, 1),
+            HexMove::NorthEast => Point(0, 1),
+            HexMove::East => Point(1, 0),
+            HexMove::SouthEast => Point(1, -1),
+            HexMove::SouthWest => Point(0, -1),
+            HexMove::West => Point(-1, 0),
+        }
+    }
+}
+
+impl<D: Direction> AddAssign<&D> for Position {
+    fn add_assign(&mut self, rhs: &D) {
+        *self += rhs.offset();
+    }
+}
+
+/// Returns the number of presents delivered to each house when the given number of actors take
+/// turns following the given moves, starting at the same house.
+pub fn presents_delivered<D: Direction>(moves: &[D], actors: usize) -> SparseGrid<u32> {
+    let mut delivery = Delivery::new(actors);
+    moves.iter().for_each(|mov| delivery.deliver(mov));
+
+    delivery.presents
+}
+
+/// The order in which actors take turns following moves. Every schedule repeats once each of its
+/// turns has been taken.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Schedule {
+    /// The given number of actors each follow one move in turn.
+    RoundRobin(usize),
+    /// Each actor follows the given number of consecutive moves in turn; `[2, 1]` has Santa follow
+    /// two moves for every move Robo-Santa follows.
+    Weighted(Vec<usize>),
+    /// The actor who follows each move, in order.
+    Explicit(Vec<usize>),
+}
+
+impl Schedule {
+    /// Returns the actor who follows each move in one repetition of this schedule.
+    pub fn turns(&self) -> Vec<usize> {
+        match self {
+            Schedule::RoundRobin(actors) => (0..*actors).collect(),
+            Schedule::Weighted(weights) => weights.iter()
+                .enumerate()
+                .flat_map(|(actor, &weight)| std::iter::repeat_n(actor, weight))
+                .collect(),
+            Schedule::Explicit(turns) => turns.clone(),
+        }
+    }
+
+    /// Returns the number of actors, including any who never get a turn.
+    pub fn actors(&self) -> usize {
+        match self {
+            Schedule::RoundRobin(actors) => *actors,
+            Schedule::Weighted(weights) => weights.len(),
+            Schedule::Explicit(turns) => turns.iter().max().map_or(0, |actor| actor + 1),
+        }
+    }
+}
+
+/// Parses a schedule written as `round-robin:N`, `weighted:W,W,...`, or `explicit:A,A,...`, where
+/// actors are numbered from zero.
+impl FromStr for Schedule {
+    type Err = ScheduleError;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let invalid = || ScheduleError::Invalid(String::from(string));
+
+        let (kind, values) = string.split_once(':').ok_or_else(invalid)?;
+        let values = values.split(',')
+            .map(|value| value.trim().parse::<usize>().map_err(|_| invalid()))
+            .collect::<Result<Vec<usize>, _>>()?;
+
+        let schedule = match (kind, values.as_slice()) {
+            ("round-robin", &[actors]) => Schedule::RoundRobin(actors),
+            ("weighted", _) => Schedule::Weighted(values),
+            ("explicit", _) => Schedule::Explicit(values),
+            _ => return Err(invalid()),
+        };
+
+        if schedule.turns().is_empty() {
+            return Err(ScheduleError::NoTurns);
+        }
+
+        Ok(schedule)
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ScheduleError {
+    Invalid(String),
+    NoTurns,
+}
+
+impl Display for ScheduleError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScheduleError::Invalid(schedule) => write!(f, "Invalid schedule {:?}; expected round-robin:N, \
+                weighted:W,W,..., or explicit:A,A,...", schedule),
+            ScheduleError::NoTurns => write!(f, "Schedule must give at least one actor a turn"),
+        }
+    }
+}
+
+impl Error for ScheduleError {}
+
+/// The houses visited by each actor when actors take turns following moves according to a
+/// schedule, starting at the same house.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Dispatch {
+    pub visited: Vec<HashSet<Position>>,
+}
+
+impl Dispatch {
+    /// Follows the given moves according to the given schedule. Moves are ignored if the schedule
+    /// gives no actor a turn.
+    pub fn new<D: Direction>(moves: &[D], schedule: &Schedule) -> Self {
+        let turns = schedule.turns();
+        let mut positions = vec![Position(0, 0); schedule.actors()];
+        let mut visited = vec![HashSet::from([Position(0, 0)]); schedule.actors()];
+
+        for (mov, &actor) in moves.iter().zip(turns.iter().cycle()) {
+            positions[actor] += mov;
+            visited[actor].insert(positions[actor]);
+        }
+
+        Dispatch { visited }
+    }
+
+    /// Returns the number of houses visited by at least one actor.
+    pub fn distinct_houses(&self) -> usize {
+        self.visited.iter().flatten().collect::<HashSet<_>>().len()
+    }
+
+    /// Returns the houses visited by more than one actor.
+    pub fn shared_houses(&self) -> HashSet<Position> {
+        let mut seen = HashSet::new();
+        let mut shared = HashSet::new();
+
+        for house in self.visited.iter().flatten() {
+            if !seen.insert(*house) {
+                shared.insert(*house);
+            }
+        }
+
+        shared
+    }
+
+    /// Returns the number of houses visited by both actors in each pair of actors, keyed by the
+    /// lower-numbered actor first.
+    pub fn overlaps(&self) -> BTreeMap<(usize, usize), usize> {
+        let mut overlaps = BTreeMap::new();
+
+        for (a, visited_a) in self.visited.iter().enumerate() {
+            for (b, visited_b) in self.visited.iter().enumerate().skip(a + 1) {
+                overlaps.insert((a, b), visited_a.intersection(visited_b).count());
+            }
+        }
+
+        overlaps
+    }
+}
+
+/// The presents delivered to each house and the route each actor took to deliver them, when the
+/// given number of actors take turns following moves one at a time.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Heatmap {
+    pub presents: SparseGrid<u32>,
+    /// Every house each actor visits, in order, starting at the first house
+    pub routes: Vec<Vec<Position>>,
+}
+
+impl Heatmap {
+    pub fn new<D: Direction>(moves: &[D], actors: NonZeroUsize) -> Self {
+        Heatmap {
+            presents: presents_delivered(moves, actors.get()),
+            routes: routes(moves, actors, |_| {}),
+        }
+    }
+
+    /// Returns the house that received the most presents and how many it received, preferring the
+    /// south-westernmost house if several received the same number.
+    pub fn most_visited(&self) -> Option<(Position, u32)> {
+        self.presents.iter()
+            .max_by_key(|&(position, &presents)| (presents, Reverse(position)))
+            .map(|(position, &presents)| (position, presents))
+    }
+
+    /// Returns the smallest rectangle containing every house that received a present.
+    pub fn bounds(&self) -> Option<Rect> {
+        self.presents.bounds()
+    }
+
+    /// Returns the number of houses that received each number of presents.
+    pub fn visit_distribution(&self) -> BTreeMap<u32, usize> {
+        let mut distribution = BTreeMap::new();
+
+        for (_, &presents) in self.presents.iter() {
+            *distribution.entry(presents).or_insert(0) += 1;
+        }
+
+        distribution
+    }
+
+    pub fn reports(&self) -> Vec<Report> {
+        let mut summary = vec![(String::from("houses"), self.presents.len().into())];
+
+        if let Some((Position(x, y), presents)) = self.most_visited() {
+            summary.push((String::from("most visited house"), format!("({}, {})", x, y).into()));
+            summary.push((String::from("most visited house presents"), presents.into()));
+        }
+
+        if let Some(bounds) = self.bounds() {
+            summary.push((String::from("bounds"), format!("({}, {}) to ({}, {})",
+                bounds.min.0, bounds.min.1, bounds.max.0, bounds.max.1).into()));
+        }
+
+        vec![
+            Report { title: "Houses", entries: summary },
+            Report {
+                title: "Houses by presents received",
+                entries: self.visit_distribution().into_iter()
+                    .map(|(presents, houses)| (format!("{} presents", presents), houses.into()))
+                    .collect(),
+            },
+        ]
+    }
+
+    /// Writes the number of presents delivered to each house as a plain (ASCII) PGM image with one
+    /// pixel per house and north at the top, where brighter houses received more presents.
+    pub fn write_pgm(&self, out: &mut dyn Write) -> io::Result<()> {
+        let Some(bounds) = self.bounds() else {
+            return writeln!(out, "P2\n0 0\n1");
+        };
+
+        let most_presents = self.most_visited().map_or(1, |(_, presents)| presents);
+        let max_value = most_presents.clamp(1, MAX_PGM_VALUE);
+
+        let pixels: BTreeMap<(usize, usize), u32> = self.presents.iter()
+            // Scale counts down only if they won't fit in the largest value a PGM image allows
+            .map(|(position, &presents)| (pixel(&bounds, position),
+                                          (presents as u64 * max_value as u64 / most_presents.max(1) as u64) as u32))
+            .collect();
+
+        writeln!(out, "P2\n{} {}\n{}", bounds.width(), bounds.height(), max_value)?;
+        write_plain_samples(out, &bounds, pixels, "0", |value| value.to_string())
+    }
+
+    /// Writes each actor's route as a plain (ASCII) PPM image with one pixel per house and north
+    /// at the top. Each actor's houses have their own colour, and houses visited by more than one
+    /// actor are white.
+    pub fn write_ppm(&self, out: &mut dyn Write) -> io::Result<()> {
+        let Some(bounds) = self.bounds() else {
+            return writeln!(out, "P3\n0 0\n255");
+        };
+
+        let mut pixels: BTreeMap<(usize, usize), Visitors> = BTreeMap::new();
+
+        for (actor, route) in self.routes.iter().enumerate() {
+            for &position in route {
+                let visitors = pixels.entry(pixel(&bounds, position)).or_default();
+
+                *visitors = match *visitors {
+                    Visitors::None => Visitors::One(actor),
+                    Visitors::One(other) if other == actor => Visitors::One(actor),
+                    _ => Visitors::Several,
+                };
+            }
+        }
+
+        writeln!(out, "P3\n{} {}\n255", bounds.width(), bounds.height())?;
+        write_plain_samples(out, &bounds, pixels, "0 0 0", |visitors| {
+            let (r, g, b) = match visitors {
+                Visitors::None => (0, 0, 0),
+                Visitors::One(actor) => ACTOR_COLOURS[actor % ACTOR_COLOURS.len()],
+                Visitors::Several => (255, 255, 255),
+            };
+
+            format!("{} {} {}", r, g, b)
+        })
+    }
+}
+
+const MAX_PGM_VALUE: u32 = 65535;
+
+const ACTOR_COLOURS: [(u8, u8, u8); 6] = [
+    (220, 40, 40),
+    (40, 180, 40),
+    (60, 90, 230),
+    (230, 200, 40),
+    (200, 60, 200),
+    (40, 200, 200),
+];
+
+/// Which actors visited a house, for drawing routes.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+enum Visitors {
+    #[default]
+    None,
+    One(usize),
+    Several,
+}
+
+/// Returns the (row, column) of the pixel for the given house in an image of the given bounds with
+/// north at the top.
+fn pixel(bounds: &Rect, position: Position) -> (usize, usize) {
+    ((bounds.max.1 - position.1) as usize, (position.0 - bounds.min.0) as usize)
+}
+
+/// Writes the samples for each pixel of a plain PNM image of the given bounds, one row per line,
+/// wrapping rows so no line is longer than the 70 characters the format allows. Pixels are given by
+/// (row, column) and any pixel without a value is drawn with the background sample, so only the
+/// pixels of visited houses are ever held in memory.
+fn write_plain_samples<T>(
+    out: &mut dyn Write,
+    bounds: &Rect,
+    pixels: BTreeMap<(usize, usize), T>,
+    background: &str,
+    sample: impl Fn(&T) -> String,
+) -> io::Result<()> {
+    let mut pixels = pixels.into_iter().peekable();
+    let mut line = String::new();
+
+    for row in 0..bounds.height() {
+        for column in 0..bounds.width() {
+            let value;
+            let sample = match pixels.next_if(|(pixel, _)| *pixel == (row, column)) {
+                Some((_, pixel)) => {
+                    value = sample(&pixel);
+                    value.as_str()
+                }
+                None => background,
+            };
+
+            if !line.is_empty() && line.len() + 1 + sample.len() > 70 {
+                writeln!(out, "{}", line)?;
+                line.clear();
+            }
+
+            if !line.is_empty() {
+                line.push(' ');
+            }
+
+            line.push_str(sample);
+        }
+
+        writeln!(out, "{}", line)?;
+        line.clear();
+    }
+
+    Ok(())
+}
+
+/// Returns every house each actor visits, in order and starting at the first house, when the given
+/// number of actors take turns following moves one at a time. Calls `visit` with each house an
+/// actor moves to, in the order of the moves.
+fn routes<D: Direction>(moves: &[D], actors: NonZeroUsize, mut visit: impl FnMut(Position)) -> Vec<Vec<Position>> {
+    let actors = actors.get();
+    let mut routes = vec![Vec::with_capacity(moves.len() / actors + 1); actors];
+
+    routes.iter_mut().for_each(|route| route.push(Position(0, 0)));
+
+    for (i, mov) in moves.iter().enumerate() {
+        let route = &mut routes[i % actors];
+        let mut position = *route.last().unwrap();
+
+        position += mov;
+        route.push(position);
+        visit(position);
+    }
+
+    routes
+}
+
+/// A replayable record of actors taking turns following moves one at a time. Step `n` is the state
+/// after the first `n` moves; at step 0, every actor is at the first house.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DeliveryLog {
+    /// Every house each actor visits, in order, starting at the first house
+    routes: Vec<Vec<Position>>,
+    /// The index of each house in `visit_starts`, in order of first visit
+    houses: HashMap<Position, usize>,
+    /// Where each house's visits start in `visit_steps`, with a final entry marking the end of the
+    /// last house's visits
+    visit_starts: Vec<usize>,
+    /// The steps at which each house was visited, grouped by house and in order within each house
+    visit_steps: Vec<usize>,
+}
+
+/// A single visit to a house.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Visit {
+    pub step: usize,
+    pub actor: usize,
+}
+
+impl DeliveryLog {
+    pub fn new<D: Direction>(moves: &[D], actors: NonZeroUsize) -> Self {
+        let mut houses = HashMap::from([(Position(0, 0), 0)]);
+        let mut house_at_step = Vec::with_capacity(moves.len() + 1);
+
+        house_at_step.push(0);
+
+        let routes = routes(moves, actors, |position| {
+            let next_house = houses.len();
+            house_at_step.push(*houses.entry(position).or_insert(next_house));
+        });
+
+        // Group steps by house with a counting sort, which keeps each house's steps in order
+        let mut visit_starts = vec![0; houses.len() + 1];
+
+        for &house in &house_at_step {
+            visit_starts[house + 1] += 1;
+        }
+
+        for house in 1..visit_starts.len() {
+            visit_starts[house] += visit_starts[house - 1];
+        }
+
+        let mut next_visit = visit_starts.clone();
+        let mut visit_steps = vec![0; house_at_step.len()];
+
+        for (step, &house) in house_at_step.iter().enumerate() {
+            visit_steps[next_visit[house]] = step;
+            next_visit[house] += 1;
+        }
+
+        DeliveryLog { routes, houses, visit_starts, visit_steps }
+    }
+
+    pub fn actors(&self) -> usize {
+        self.routes.len()
+    }
+
+    /// Returns the number of moves followed.
+    pub fn steps(&self) -> usize {
+        self.visit_steps.len() - 1
+    }
+
+    pub fn distinct_houses(&self) -> usize {
+        self.houses.len()
+    }
+
+    /// Returns the actor that followed the move taken at the given step, or actor 0 for step 0.
+    pub fn actor_at(&self, step: usize) -> usize {
+        step.saturating_sub(1) % self.actors()
+    }
+
+    /// Returns where the given actor was at the given step, or `None` if there's no such actor or
+    /// step.
+    pub fn position_at(&self, actor: usize, step: usize) -> Option<Position> {
+        if actor >= self.actors() || step > self.steps() {
+            return None;
+        }
+
+        let moves_taken = if step > actor { (step - actor - 1) / self.actors() + 1 } else { 0 };
+        Some(self.routes[actor][moves_taken])
+    }
+
+    /// Returns every visit to the given house in order, counting the start as a single visit by
+    /// actor 0.
+    pub fn visits(&self, house: Position) -> impl Iterator<Item = Visit> + '_ {
+        let steps = match self.houses.get(&house) {
+            Some(&index) => &self.visit_steps[self.visit_starts[index]..self.visit_starts[index + 1]],
+            None => &[],
+        };
+
+        steps.iter().map(|&step| Visit { step, actor: self.actor_at(step) })
+    }
+
+    pub fn first_visit(&self, house: Position) -> Option<Visit> {
+        self.visits(house).next()
+    }
+
+    /// Returns the number of steps between each visit to the given house and the next.
+    pub fn revisit_intervals(&self, house: Position) -> Vec<usize> {
+        let steps: Vec<usize> = self.visits(house).map(|visit| visit.step).collect();
+        steps.windows(2).map(|pair| pair[1] - pair[0]).collect()
+    }
+}
+
+/// Presents delivered so far by actors that take turns following moves one at a time.
+struct Delivery {
+    presents: SparseGrid<u32>,
+    positions: Vec<Position>,
+    moves: usize,
+}
+
+impl Delivery {
+    fn new(actors: usize) -> Self {
+        let mut presents = SparseGrid::new();
+        *presents.entry(Position(0, 0)) += actors as u32;
+
+        Delivery { presents, positions: vec![Position(0, 0); actors], moves: 0 }
+    }
+
+    fn deliver<D: Direction>(&mut self, mov: &D) {
+        let actors = self.positions.len();
+        let position = &mut self.positions[self.moves % actors];
+
+        *position += mov;
+        *self.presents.entry(*position) += 1;
+        self.moves += 1;
+    }
+}
+
+pub fn distinct_houses_visited<D: Direction>(moves: &[D], actors: usize) -> u32 {
+    presents_delivered(moves, actors).len() as u32
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_distinct_houses_visited() {
+        assert_eq!(2, distinct_houses_visited(&[East], 1));
+        assert_eq!(4, distinct_houses_visited(&[North, East, South, West], 1));
+        assert_eq!(2, distinct_houses_visited(
+            &[North, South, North, South, North, South, North, South, North, South], 1));
+
+        assert_eq!(3, distinct_houses_visited(&[North, South], 2));
+        assert_eq!(3, distinct_houses_visited(&[North, East, South, West], 2));
+        assert_eq!(11, distinct_houses_visited(
+            &[North, South, North, South, North, South, North, South, North, South], 2));
+    }
+
+    #[test]
+    fn test_presents_delivered() {
+        let presents = presents_delivered(&[North, East, South, West], 2);
+
+        assert_eq!(Some(&4), presents.get(Position(0, 0)));
+        assert_eq!(Some(&1), presents.get(Position(0, 1)));
+        assert_eq!(Some(&1), presents.get(Position(1, 0)));
+        assert_eq!(None, presents.get(Position(1, 1)));
+    }
+
+    #[test]
+    fn test_solve_streaming() {
+        let streamed = Day03::solve_streaming(&mut "^v^v^v^v^v\n".as_bytes(), None, ParseMode::Strict).unwrap();
+        assert_eq!(Some(Answer::from(2u32)), streamed.part_one);
+        assert_eq!(Some(Answer::from(11u32)), streamed.part_two);
+
+        let error = Day03::solve_streaming(&mut "^^ >".as_bytes(), None, ParseMode::Strict).unwrap_err()
+            .downcast::<ParseError>()
+            .unwrap();
+        assert_eq!(ErrorKind::IllegalDirection(' '), error.kind);
+        assert_eq!(3, error.column);
+
+        let streamed = Day03::solve_streaming(&mut "^^x>".as_bytes(), None, ParseMode::Lenient).unwrap();
+        assert_eq!(Some(Answer::from(4u32)), streamed.part_one);
+        assert_eq!(1, streamed.skipped.len());
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(vec![North, South, East, West], Day03::parse("^v><\n").unwrap());
+
+        let error = Day03::parse("^^x>").unwrap_err().downcast::<ParseError>().unwrap();
+        assert_eq!(ErrorKind::IllegalDirection('x'), error.kind);
+        assert_eq!(3, error.column);
+    }
+
+    #[test]
+    fn test_schedule_from_string() {
+        assert_eq!(Ok(Schedule::RoundRobin(3)), Schedule::from_str("round-robin:3"));
+        assert_eq!(Ok(Schedule::Weighted(vec![2, 1])), Schedule::from_str("weighted:2,1"));
+        assert_eq!(Ok(Schedule::Explicit(vec![0, 0, 2])), Schedule::from_str("explicit:0,0,2"));
+
+        assert_eq!(vec![0, 0, 1], Schedule::Weighted(vec![2, 1]).turns());
+        assert_eq!(3, Schedule::Explicit(vec![0, 0, 2]).actors());
+
+        assert_eq!(Err(ScheduleError::NoTurns), Schedule::from_str("weighted:0,0"));
+        assert_eq!(Err(ScheduleError::NoTurns), Schedule::from_str("round-robin:0"));
+        assert!(Schedule::from_str("round-robin:1,2").is_err());
+        assert!(Schedule::from_str("random:1").is_err());
+        assert!(Schedule::from_str("explicit").is_err());
+    }
+
+    #[test]
+    fn test_delivery_log() {
+        // Santa goes north twice and back once, while Robo-Santa goes east, back, and south
+        let log = DeliveryLog::new(&[North, East, North, West, South, South], NonZeroUsize::new(2).unwrap());
+
+        assert_eq!(6, log.steps());
+        assert_eq!(5, log.distinct_houses());
+
+        assert_eq!(Some(Position(0, 0)), log.position_at(1, 1));
+        assert_eq!(Some(Position(1, 0)), log.position_at(1, 2));
+        assert_eq!(Some(Position(0, 2)), log.position_at(0, 4));
+        assert_eq!(Some(Position(0, 1)), log.position_at(0, 6));
+        assert_eq!(None, log.position_at(0, 7));
+        assert_eq!(None, log.position_at(2, 0));
+
+        assert_eq!(Some(Visit { step: 2, actor: 1 }), log.first_visit(Position(1, 0)));
+        assert_eq!(Some(Visit { step: 1, actor: 0 }), log.first_visit(Position(0, 1)));
+        assert_eq!(None, log.first_visit(Position(5, 5)));
+
+        assert_eq!(vec![Visit { step: 0, actor: 0 }, Visit { step: 4, actor: 1 }], log.visits(Position(0, 0)).collect::<Vec<_>>());
+        assert_eq!(vec![4], log.revisit_intervals(Position(0, 1)));
+        assert!(log.revisit_intervals(Position(0, 2)).is_empty());
+    }
+
+    #[test]
+    fn test_dispatch() {
+        // Santa follows two moves for every one Robo-Santa follows
+        let dispatch = Dispatch::new(&[North, North, East, South, South, West], &Schedule::Weighted(vec![2, 1]));
+
+        assert_eq!(HashSet::from([Position(0, 0), Position(0, 1), Position(0, 2)]), dispatch.visited[0]);
+        assert_eq!(HashSet::from([Position(0, 0), Position(1, 0)]), dispatch.visited[1]);
+        assert_eq!(4, dispatch.distinct_houses());
+        assert_eq!(HashSet::from([Position(0, 0)]), dispatch.shared_houses());
+        assert_eq!(BTreeMap::from([((0, 1), 1)]), dispatch.overlaps());
+
+        let dispatch = Dispatch::new(&[North, East, South], &Schedule::Explicit(vec![0, 2]));
+        assert_eq!(3, dispatch.visited.len());
+        assert_eq!(1, dispatch.visited[1].len());
+        assert_eq!(BTreeMap::from([((0, 1), 1), ((0, 2), 1), ((1, 2), 1)]), dispatch.overlaps());
+    }
+
+    #[test]
+    fn test_heatmap() {
+        let heatmap = Heatmap::new(&[North, East, South, West, North, North], NonZeroUsize::MIN);
+
+        assert_eq!(vec![
+            Position(0, 0), Position(0, 1), Position(1, 1), Position(1, 0),
+            Position(0, 0), Position(0, 1), Position(0, 2),
+        ], heatmap.routes[0]);
+
+        assert_eq!(Some((Position(0, 0), 2)), heatmap.most_visited());
+        assert_eq!(Some(Rect::new(Position(0, 0), Position(1, 2))), heatmap.bounds());
+        assert_eq!(BTreeMap::from([(1, 3), (2, 2)]), heatmap.visit_distribution());
+    }
+
+    #[test]
+    fn test_heatmap_images() {
+        let heatmap = Heatmap::new(&[North, East, East, South], NonZeroUsize::new(2).unwrap());
+
+        let mut pgm = Vec::new();
+        heatmap.write_pgm(&mut pgm).unwrap();
+        assert_eq!("P2\n2 3\n2\n1 1\n2 1\n0 1\n", String::from_utf8(pgm).unwrap());
+
+        let mut ppm = Vec::new();
+        heatmap.write_ppm(&mut ppm).unwrap();
+        assert_eq!(
+            "P3\n2 3\n255\n220 40 40 220 40 40\n255 255 255 40 180 40\n0 0 0 40 180 40\n",
+            String::from_utf8(ppm).unwrap()
+        );
+
+        // Long rows wrap so that no line is longer than 70 characters
+        let heatmap = Heatmap::new(&[East; 40], NonZeroUsize::MIN);
+        let mut ppm = Vec::new();
+        heatmap.write_ppm(&mut ppm).unwrap();
+
+        let ppm = String::from_utf8(ppm).unwrap();
+        assert!(ppm.lines().all(|line| line.len() <= 70));
+        assert_eq!(41 * 3, ppm.lines().skip(3).flat_map(str::split_whitespace).count());
+
+        // Houses that weren't visited are drawn as background, including whole empty rows
+        let heatmap = Heatmap {
+            presents: SparseGrid::from_iter([(Position(0, 0), 2), (Position(3, -2), 1)]),
+            routes: vec![vec![Position(0, 0), Position(3, -2)]],
+        };
+
+        let mut pgm = Vec::new();
+        heatmap.write_pgm(&mut pgm).unwrap();
+        assert_eq!("P2\n4 3\n2\n2 0 0 0\n0 0 0 0\n0 0 0 1\n", String::from_utf8(pgm).unwrap());
+    }
+
+    #[test]
+    fn test_compass_moves() {
+        let (moves, _) = parse_moves::<CompassMove>("9173^", ParseMode::Strict).unwrap();
+
+        assert_eq!(vec![CompassMove::NorthEast, CompassMove::SouthWest, CompassMove::NorthWest,
+                        CompassMove::SouthEast, CompassMove::North], moves);
+        assert_eq!(4, distinct_houses_visited(&moves, 1));
+    }
+
+    #[test]
+    fn test_hex_moves() {
+        // Walking around all six sides of a hexagon leads back to the first house
+        let (moves, _) = parse_moves::<HexMove>("edxzaw", ParseMode::Strict).unwrap();
+        let heatmap = Heatmap::new(&moves, NonZeroUsize::MIN);
+
+        assert_eq!(6, distinct_houses_visited(&moves, 1));
+        assert_eq!(Some(&Position(0, 0)), heatmap.routes[0].last());
+        assert_eq!(Some((Position(0, 0), 2)), heatmap.most_visited());
+
+        let mut position = Position(0, 0);
+        position += &HexMove::NorthEast;
+        position += &HexMove::SouthEast;
+        assert_eq!(Position(0, 0) + HexMove::East.offset(), position);
+
+        let error = parse_moves::<HexMove>("ed^", ParseMode::Strict).unwrap_err();
+        assert_eq!(ErrorKind::IllegalDirection('^'), error.kind);
+        assert_eq!(3, error.column);
+
+        let (moves, skipped) = parse_moves::<HexMove>("e^d", ParseMode::Lenient).unwrap();
+        assert_eq!(vec![HexMove::NorthEast, HexMove::East], moves);
+        assert_eq!(1, skipped.len());
+    }
+
+    fn moves() -> impl Strategy<Value = Vec<Move>> {
+        prop::collection::vec(prop_oneof![Just(North), Just(South), Just(East), Just(West)], 0..200)
+    }
+
+    proptest! {
+        #[test]
+        fn test_distinct_houses_visited_bounds(moves in moves(), actors in 1..4usize) {
+            let visited = distinct_houses_visited(&moves, actors) as usize;
+
+            prop_assert!(visited >= 1);
+            prop_assert!(visited <= moves.len() + 1);
+        }
+
+        #[test]
+        fn test_four_way_routes_are_eight_way_routes(route in "[v^<>]{0,200}", actors in 1..4usize) {
+            let (moves, _) = parse_moves::<Move>(&route, ParseMode::Strict).unwrap();
+            let (compass_moves, _) = parse_moves::<CompassMove>(&route, ParseMode::Strict).unwrap();
+
+            prop_assert_eq!(presents_delivered(&moves, actors), presents_delivered(&compass_moves, actors));
+        }
+
+        #[test]
+        fn test_round_robin_dispatch_matches_presents_delivered(moves in moves(), actors in 1..4usize) {
+            let dispatch = Dispatch::new(&moves, &Schedule::RoundRobin(actors));
+
+            prop_assert_eq!(distinct_houses_visited(&moves, actors) as usize, dispatch.distinct_houses());
+            prop_assert_eq!(dispatch.visited, Dispatch::new(&moves, &Schedule::Weighted(vec![1; actors])).visited);
+        }
+
+        #[test]
+        fn test_delivery_log_replays_routes(moves in moves(), actors in 1..4usize) {
+            let log = DeliveryLog::new(&moves, NonZeroUsize::new(actors).unwrap());
+            let heatmap = Heatmap::new(&moves, NonZeroUsize::new(actors).unwrap());
+
+            prop_assert_eq!(distinct_houses_visited(&moves, actors) as usize, log.distinct_houses());
+
+            for (actor, route) in heatmap.routes.iter().enumerate() {
+                prop_assert_eq!(route.last().copied(), log.position_at(actor, log.steps()));
+            }
+
+            for (house, &presents) in heatmap.presents.iter() {
+                let visits: Vec<Visit> = log.visits(house).collect();
+
+                // The first house gets a present from every actor at the start, but only one visit
+                let extra_presents = if house == Position(0, 0) { actors - 1 } else { 0 };
+                prop_assert_eq!(presents as usize, visits.len() + extra_presents);
+
+                for visit in visits {
+                    prop_assert_eq!(Some(house), log.position_at(visit.actor, visit.step));
+                }
+            }
+        }
+
+        #[test]
+        fn test_parse_does_not_panic(input in "\\PC*") {
+            let _ = Day03::parse(&input);
+            let _ = Day03::parse_lenient(&input);
+        }
+    }
+}
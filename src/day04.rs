@@ -0,0 +1,158 @@
+use std::error::Error;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::thread;
+use crate::{Answer, Solution};
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input, Box<dyn Error>> {
+        Ok(String::from(input.trim()))
+    }
+
+    fn part_one(key: &Self::Input) -> Answer {
+        find_advent_coin(key, 5, num_cpus::get()).into()
+    }
+
+    fn part_two(key: &Self::Input) -> Option<Answer> {
+        Some(find_advent_coin(key, 6, num_cpus::get()).into())
+    }
+}
+
+/// How many nonces each thread claims at a time
+const BATCH_SIZE: u64 = 256;
+
+/// Returns the lowest positive nonce whose MD5 hash, when appended to the given key, starts with the
+/// given number of zeroes in hexadecimal. The same nonce is returned no matter how many threads
+/// search for it.
+///
+/// Panics if no nonce up to `u32::MAX` works.
+pub fn find_advent_coin(key: &str, leading_zeroes: usize, threads: usize) -> u32 {
+    // Every hash starts with the key, so the key only needs to be hashed once
+    let mut key_state = md5::Context::new();
+    key_state.consume(key);
+
+    let next_batch = AtomicU64::new(1);
+    let lowest_coin = AtomicU64::new(u64::MAX);
+
+    thread::scope(|scope| {
+        for _ in 0..threads.max(1) {
+            scope.spawn(|| loop {
+                // Batches are claimed in order, so once a claimed batch starts past the lowest coin
+                // found so far, every lower nonce has already been claimed by some thread
+                let start = next_batch.fetch_add(BATCH_SIZE, Ordering::Relaxed);
+
+                if start > u32::MAX as u64 || start > lowest_coin.load(Ordering::Relaxed) {
+                    break;
+                }
+
+                let mut digits = [0; 20];
+
+                for n in start..(start + BATCH_SIZE).min(u32::MAX as u64 + 1) {
+                    let mut state = key_state.clone();
+                    state.consume(decimal_digits(n, &mut digits));
+
+                    if has_leading_zeroes(&state.compute(), leading_zeroes) {
+                        lowest_coin.fetch_min(n, Ordering::Relaxed);
+                        break;
+                    }
+                }
+            });
+        }
+    });
+
+    u32::try_from(lowest_coin.into_inner()).expect("No advent coin has a nonce that fits in a u32")
+}
+
+/// Writes the decimal digits of the given number to the end of the given buffer, returning just
+/// the digits.
+fn decimal_digits(mut n: u64, buffer: &mut [u8; 20]) -> &[u8] {
+    let mut start = buffer.len();
+
+    loop {
+        start -= 1;
+        buffer[start] = b'0' + (n % 10) as u8;
+        n /= 10;
+
+        if n == 0 {
+            return &buffer[start..];
+        }
+    }
+}
+
+/// Returns true if the given digest starts with at least the given number of zeroes when written
+/// in hexadecimal, checking the digest's nibbles directly instead of formatting it.
+fn has_leading_zeroes(digest: &[u8; 16], zeroes: usize) -> bool {
+    if zeroes > digest.len() * 2 {
+        return false;
+    }
+
+    let (whole_bytes, odd_nibble) = (zeroes / 2, zeroes % 2 == 1);
+
+    digest[..whole_bytes].iter().all(|&byte| byte == 0)
+        && (!odd_nibble || digest[whole_bytes] >> 4 == 0)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_find_advent_coin() {
+        assert_eq!(609043, find_advent_coin("abcdef", 5, num_cpus::get()));
+        assert_eq!(1048970, find_advent_coin("pqrstuv", 5, num_cpus::get()));
+    }
+
+    #[test]
+    fn test_find_advent_coin_returns_lowest_coin() {
+        let lowest_coin = |leading_zeroes| (1..)
+            .find(|n| format!("{:x}", md5::compute(format!("abcdef{}", n))).starts_with(&"0".repeat(leading_zeroes)))
+            .unwrap();
+
+        for leading_zeroes in 1..=4 {
+            let expected = lowest_coin(leading_zeroes);
+
+            for threads in 1..=8 {
+                assert_eq!(expected, find_advent_coin("abcdef", leading_zeroes, threads),
+                           "{} leading zeroes with {} threads", leading_zeroes, threads);
+            }
+        }
+    }
+
+    #[test]
+    fn test_has_leading_zeroes() {
+        let mut digest = [0xff; 16];
+        digest[0] = 0x00;
+        digest[1] = 0x00;
+        digest[2] = 0x0f;
+
+        assert!(has_leading_zeroes(&digest, 0));
+        assert!(has_leading_zeroes(&digest, 4));
+        assert!(has_leading_zeroes(&digest, 5));
+        assert!(!has_leading_zeroes(&digest, 6));
+
+        assert!(has_leading_zeroes(&[0; 16], 32));
+        assert!(!has_leading_zeroes(&[0; 16], 33));
+    }
+
+    proptest! {
+        #[test]
+        fn test_has_leading_zeroes_matches_hex(digest in any::<[u8; 16]>(), zeroes in 0..=33usize) {
+            // Favor digests that actually start with some zeroes
+            let mut digest = digest;
+            digest[..zeroes.min(32) / 3].fill(0);
+
+            let hex: String = digest.iter().map(|byte| format!("{:02x}", byte)).collect();
+            prop_assert_eq!(hex.starts_with(&"0".repeat(zeroes)), has_leading_zeroes(&digest, zeroes));
+        }
+
+        #[test]
+        fn test_decimal_digits(n in any::<u64>()) {
+            let mut buffer = [0; 20];
+            prop_assert_eq!(n.to_string(), String::from_utf8_lossy(decimal_digits(n, &mut buffer)));
+        }
+    }
+}
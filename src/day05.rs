@@ -1,32 +1,54 @@
-use std::{env, error, io};
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::error::Error;
+use std::io::{self, BufRead};
 use itertools::Itertools;
+use crate::{stream, Answer, ParseMode, Part, Solution, Streamed, Streaming};
 
-fn main() -> Result<(), Box<dyn error::Error>> {
-    let args: Vec<String> = env::args().collect();
+pub struct Day05;
 
-    if let Some(path) = args.get(1) {
-        let file = File::open(path)?;
+impl Solution for Day05 {
+    type Input = Vec<String>;
 
-        let strings: Vec<String> = BufReader::new(file).lines()
-            .collect::<io::Result<Vec<String>>>()?;
+    fn parse(input: &str) -> Result<Self::Input, Box<dyn Error>> {
+        Ok(input.lines().map(String::from).collect())
+    }
 
-        println!("Nice strings: {}", strings.iter()
+    fn part_one(strings: &Self::Input) -> Answer {
+        strings.iter()
             .filter(|string| is_nice(string))
-            .count());
+            .count()
+            .into()
+    }
 
-        println!("More different nice strings: {}", strings.iter()
+    fn part_two(strings: &Self::Input) -> Option<Answer> {
+        Some(strings.iter()
             .filter(|string| more_different_is_nice(string))
-            .count());
+            .count()
+            .into())
+    }
+}
+
+impl Streaming for Day05 {
+    fn solve_streaming(input: &mut dyn BufRead, part: Option<Part>, _mode: ParseMode) -> Result<Streamed, Box<dyn Error>> {
+        let mut nice = Part::One.is_selected(part).then_some(0usize);
+        let mut more_different_nice = Part::Two.is_selected(part).then_some(0usize);
+
+        stream::for_each_line(input, |_, string| -> io::Result<()> {
+            if let Some(nice) = nice.as_mut() {
+                *nice += is_nice(string) as usize;
+            }
+
+            if let Some(more_different_nice) = more_different_nice.as_mut() {
+                *more_different_nice += more_different_is_nice(string) as usize;
+            }
+
+            Ok(())
+        })?;
 
-        Ok(())
-    } else {
-        Err("Usage: day05 INPUT_FILE_PATH".into())
+        Ok(Streamed { part_one: nice.map(Answer::from), part_two: more_different_nice.map(Answer::from), skipped: Vec::new() })
     }
 }
 
-fn is_nice(string: &str) -> bool {
+pub fn is_nice(string: &str) -> bool {
     const FORBIDDEN_SUBSTRINGS: [&str; 4] = ["ab", "cd", "pq", "xy"];
 
     let vowels = string.chars()
@@ -55,7 +77,7 @@ fn is_nice(string: &str) -> bool {
     true
 }
 
-fn more_different_is_nice(string: &str) -> bool {
+pub fn more_different_is_nice(string: &str) -> bool {
     has_repeated_non_overlapping_pair(string) && has_straddling_pair(string)
 }
 
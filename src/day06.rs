@@ -0,0 +1,335 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io::BufRead;
+use std::str::FromStr;
+use lazy_static::lazy_static;
+use regex::Regex;
+use crate::{error, stream, Answer, Lenient, ParseMode, Part, Solution, Streamed, Streaming};
+use crate::grid::{DenseGrid, OutOfBounds, Rect};
+
+pub use crate::grid::Point as Position;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Self::Input, Box<dyn Error>> {
+        Ok(error::parse_lines(input, ParseMode::Strict, parse_instruction)?.0)
+    }
+
+    fn parse_lenient(input: &str) -> Result<Lenient<Self::Input>, Box<dyn Error>> {
+        let (instructions, skipped) = error::parse_lines(input, ParseMode::Lenient, parse_instruction)?;
+        Ok(Lenient::new(instructions, skipped))
+    }
+
+    fn part_one(instructions: &Self::Input) -> Answer {
+        let mut light_grid = LightGrid::new(GRID_SIZE, GRID_SIZE);
+
+        instructions.iter()
+            .for_each(|instruction| light_grid.apply(instruction).expect("Instructions should fit within the grid"));
+
+        light_grid.total_brightness().into()
+    }
+
+    fn part_two(instructions: &Self::Input) -> Option<Answer> {
+        let mut light_grid = LightGrid::new(GRID_SIZE, GRID_SIZE);
+
+        instructions.iter()
+            .for_each(|instruction| light_grid.apply_ancient_nordic(instruction)
+                .expect("Instructions should fit within the grid"));
+
+        Some(light_grid.total_brightness().into())
+    }
+}
+
+impl Streaming for Day06 {
+    fn solve_streaming(input: &mut dyn BufRead, part: Option<Part>, mode: ParseMode) -> Result<Streamed, Box<dyn Error>> {
+        let mut light_grid = Part::One.is_selected(part).then(|| LightGrid::new(GRID_SIZE, GRID_SIZE));
+        let mut ancient_nordic_light_grid = Part::Two.is_selected(part).then(|| LightGrid::new(GRID_SIZE, GRID_SIZE));
+
+        let skipped = stream::parse_lines(input, mode, parse_instruction, |instruction| {
+            if let Some(light_grid) = light_grid.as_mut() {
+                light_grid.apply(&instruction).expect("Instructions should fit within the grid");
+            }
+
+            if let Some(ancient_nordic_light_grid) = ancient_nordic_light_grid.as_mut() {
+                ancient_nordic_light_grid.apply_ancient_nordic(&instruction).expect("Instructions should fit within the grid");
+            }
+        })?;
+
+        Ok(Streamed {
+            part_one: light_grid.map(|light_grid| light_grid.total_brightness().into()),
+            part_two: ancient_nordic_light_grid.map(|light_grid| light_grid.total_brightness().into()),
+            skipped: error::boxed(skipped),
+        })
+    }
+}
+
+pub const GRID_SIZE: usize = 1000;
+
+/// Parses an instruction and checks that it fits within the puzzle's grid of lights.
+fn parse_instruction(line: &str) -> Result<Instruction, ParseError> {
+    let instruction = Instruction::from_str(line)?;
+    let bounds = Rect::new(Position(0, 0), Position(GRID_SIZE as i32 - 1, GRID_SIZE as i32 - 1));
+
+    if bounds.contains_rect(&instruction.rect()) {
+        Ok(instruction)
+    } else {
+        let end = instruction.end;
+        let text = line.rfind(&format!("{},{}", end.0, end.1)).map_or(line, |i| &line[i..]);
+
+        Err(ParseError::new(ErrorKind::OutOfBounds, line, text))
+    }
+}
+
+pub struct LightGrid {
+    lights: DenseGrid<u64>,
+}
+
+impl LightGrid {
+    pub fn new(width: usize, height: usize) -> Self {
+        LightGrid { lights: DenseGrid::new(width, height) }
+    }
+
+    pub fn apply(&mut self, instruction: &Instruction) -> Result<(), OutOfBounds> {
+        self.lights.update_rect(&instruction.rect(), |light| {
+            *light = match instruction.operation {
+                Operation::On => 1,
+                Operation::Off => 0,
+                Operation::Toggle => match *light {
+                    0 => 1,
+                    _ => 0,
+                }
+            };
+        })
+    }
+
+    pub fn apply_ancient_nordic(&mut self, instruction: &Instruction) -> Result<(), OutOfBounds> {
+        self.lights.update_rect(&instruction.rect(), |light| {
+            *light = match instruction.operation {
+                Operation::On => *light + 1,
+                Operation::Off => light.saturating_sub(1),
+                Operation::Toggle => *light + 2,
+            };
+        })
+    }
+
+    pub fn total_brightness(&self) -> u64 {
+        self.lights.values().sum()
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Operation {
+    On,
+    Off,
+    Toggle
+}
+
+impl FromStr for Operation {
+    type Err = ParseError;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        match string {
+            "turn on" => Ok(Operation::On),
+            "turn off" => Ok(Operation::Off),
+            "toggle" => Ok(Operation::Toggle),
+            _ => Err(ParseError::new(ErrorKind::UnrecognizedOperation, string, string))
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Instruction {
+    pub operation: Operation,
+    pub start: Position,
+    pub end: Position,
+}
+
+impl Instruction {
+    pub fn rect(&self) -> Rect {
+        Rect::new(self.start, self.end)
+    }
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        lazy_static! {
+            static ref INSTRUCTION_REGEX: Regex =
+                Regex::new(r"(turn on|turn off|toggle) (\d+),(\d+) through (\d+),(\d+)").unwrap();
+        }
+
+        if let Some(captures) = INSTRUCTION_REGEX.captures(s) {
+            let coordinate = |i: usize| -> Result<i32, ParseError> {
+                captures[i].parse()
+                    .map_err(|_| ParseError::new(ErrorKind::InvalidCoordinate, s, &captures[i]))
+            };
+
+            let operation = Operation::from_str(&captures[1])
+                .map_err(|error| error.within(s, &captures[1]))?;
+            let start = Position(coordinate(2)?, coordinate(3)?);
+            let end = Position(coordinate(4)?, coordinate(5)?);
+
+            if start.0 > end.0 || start.1 > end.1 {
+                let range = &s[captures.get(2).unwrap().start()..captures.get(5).unwrap().end()];
+                return Err(ParseError::new(ErrorKind::StartAfterEnd, s, range));
+            }
+
+            Ok(Instruction { operation, start, end })
+        } else {
+            Err(ParseError::new(ErrorKind::MalformedInstruction, s, s))
+        }
+    }
+}
+
+pub type ParseError = error::ParseError<ErrorKind>;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ErrorKind {
+    MalformedInstruction,
+    UnrecognizedOperation,
+    InvalidCoordinate,
+    StartAfterEnd,
+    OutOfBounds,
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ErrorKind::MalformedInstruction => write!(f, "Could not parse instruction"),
+            ErrorKind::UnrecognizedOperation => write!(f, "Unrecognized operation"),
+            ErrorKind::InvalidCoordinate => write!(f, "Invalid coordinate"),
+            ErrorKind::StartAfterEnd => write!(f, "Start after end"),
+            ErrorKind::OutOfBounds => write!(f, "Coordinate outside of the grid"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_instruction_from_string() {
+        assert_eq!(
+            Instruction { operation: Operation::On, start: Position(0, 0), end: Position(999, 999) },
+            Instruction::from_str("turn on 0,0 through 999,999").unwrap()
+        );
+
+        assert_eq!(
+            Instruction { operation: Operation::Toggle, start: Position(0, 0), end: Position(999, 0) },
+            Instruction::from_str("toggle 0,0 through 999,0").unwrap()
+        );
+
+        assert_eq!(
+            Instruction { operation: Operation::Off, start: Position(499, 499), end: Position(500, 500) },
+            Instruction::from_str("turn off 499,499 through 500,500").unwrap()
+        );
+
+        let error = Instruction::from_str("turn on 5,5 through 4,9").unwrap_err();
+        assert_eq!(ErrorKind::StartAfterEnd, error.kind);
+        assert_eq!(9, error.column);
+        assert_eq!("5,5 through 4,9", error.text);
+
+        let error = Instruction::from_str("turn sideways 0,0 through 1,1").unwrap_err();
+        assert_eq!(ErrorKind::MalformedInstruction, error.kind);
+    }
+
+    #[test]
+    fn test_apply_total_brightness() {
+        let mut light_grid = LightGrid::new(1000, 1000);
+        assert_eq!(0, light_grid.total_brightness());
+
+        light_grid.apply(&Instruction { operation: Operation::On, start: Position(0, 0), end: Position(999, 999) }).unwrap();
+        assert_eq!(1_000_000, light_grid.total_brightness());
+
+        light_grid.apply(&Instruction { operation: Operation::Toggle, start: Position(0, 0), end: Position(999, 0) }).unwrap();
+        assert_eq!(999_000, light_grid.total_brightness());
+
+        light_grid.apply(&Instruction { operation: Operation::Toggle, start: Position(0, 0), end: Position(999, 0) }).unwrap();
+        assert_eq!(1_000_000, light_grid.total_brightness());
+
+        light_grid.apply(&Instruction { operation: Operation::Off, start: Position(499, 499), end: Position(500, 500) }).unwrap();
+        assert_eq!(999_996, light_grid.total_brightness());
+    }
+
+    #[test]
+    fn test_total_brightness_does_not_overflow() {
+        let mut light_grid = LightGrid { lights: DenseGrid::filled(1000, 1000, u32::MAX as u64) };
+        light_grid.apply_ancient_nordic(&Instruction { operation: Operation::Toggle, start: Position(0, 0), end: Position(999, 999) }).unwrap();
+
+        assert_eq!(1_000_000 * (u32::MAX as u64 + 2), light_grid.total_brightness());
+    }
+
+    #[test]
+    fn test_apply_out_of_bounds() {
+        let mut light_grid = LightGrid::new(10, 10);
+        let instruction = Instruction { operation: Operation::On, start: Position(5, 5), end: Position(10, 9) };
+
+        assert_eq!(Position(10, 9), light_grid.apply(&instruction).unwrap_err().point);
+        assert_eq!(0, light_grid.total_brightness());
+    }
+
+    #[test]
+    fn test_parse_out_of_bounds() {
+        let error = Day06::parse("turn on 0,0 through 999,999\ntoggle 5,5 through 1000,5\n")
+            .unwrap_err()
+            .downcast::<ParseError>()
+            .unwrap();
+
+        assert_eq!(ErrorKind::OutOfBounds, error.kind);
+        assert_eq!(2, error.line);
+        assert_eq!(20, error.column);
+        assert_eq!("1000,5", error.text);
+    }
+
+    fn instruction() -> impl Strategy<Value = Instruction> {
+        (
+            prop_oneof![Just(Operation::On), Just(Operation::Off), Just(Operation::Toggle)],
+            0..10i32, 0..10i32, 0..10i32, 0..10i32,
+        ).prop_map(|(operation, x1, y1, x2, y2)| Instruction {
+            operation,
+            start: Position(x1.min(x2), y1.min(y2)),
+            end: Position(x1.max(x2), y1.max(y2)),
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn test_apply_brightness_bounds(instructions in prop::collection::vec(instruction(), 0..20)) {
+            let mut light_grid = LightGrid::new(10, 10);
+            instructions.iter().for_each(|instruction| light_grid.apply(instruction).unwrap());
+
+            prop_assert!(light_grid.total_brightness() <= 100);
+        }
+
+        #[test]
+        fn test_apply_ancient_nordic_brightness_never_negative(
+            instructions in prop::collection::vec(instruction(), 0..20)
+        ) {
+            // Turning off lights that are already off must not underflow (which would panic in
+            // debug builds) or otherwise reduce brightness below zero
+            let mut light_grid = LightGrid::new(10, 10);
+
+            for instruction in &instructions {
+                let before = light_grid.total_brightness();
+                light_grid.apply_ancient_nordic(instruction).unwrap();
+
+                if instruction.operation == Operation::Off {
+                    prop_assert!(light_grid.total_brightness() <= before);
+                } else {
+                    prop_assert!(light_grid.total_brightness() > before);
+                }
+            }
+        }
+
+        #[test]
+        fn test_parse_does_not_panic(input in "\\PC*") {
+            let _ = Day06::parse(&input);
+        }
+    }
+}
@@ -0,0 +1,417 @@
+use std::collections::{HashMap, HashSet};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io::BufRead;
+use std::str::FromStr;
+use crate::{error, stream, Answer, Lenient, ParseMode, Solution, StreamingParse};
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Circuit;
+
+    fn parse(input: &str) -> Result<Self::Input, Box<dyn Error>> {
+        let (connections, _) = error::parse_lines(input, ParseMode::Strict, parse_connection)?;
+        Ok(with_wire_a(connections.into_iter().collect(), input.lines().count())?)
+    }
+
+    fn parse_lenient(input: &str) -> Result<Lenient<Self::Input>, Box<dyn Error>> {
+        let (connections, skipped) = error::parse_lines(input, ParseMode::Lenient, parse_connection)?;
+        let circuit = with_wire_a(connections.into_iter().collect(), input.lines().count())?;
+
+        Ok(Lenient::new(circuit, skipped))
+    }
+
+    fn part_one(circuit: &Self::Input) -> Answer {
+        wire_a_value(&mut circuit.clone()).into()
+    }
+
+    fn part_two(circuit: &Self::Input) -> Option<Answer> {
+        let mut circuit = circuit.clone();
+        let signal = wire_a_value(&mut circuit);
+
+        // Now, take the signal you got on wire a, override wire b to that signal, and reset the
+        // other wires (including wire a). What new signal is ultimately provided to wire a?
+        circuit.set_input("b", Input::DirectSource(Source::Signal(signal)));
+
+        Some(wire_a_value(&mut circuit).into())
+    }
+}
+
+impl StreamingParse for Day07 {
+    fn parse_streaming(input: &mut dyn BufRead, mode: ParseMode) -> Result<Lenient<Self::Input>, Box<dyn Error>> {
+        let mut connections = Vec::new();
+        let skipped = stream::parse_lines(input, mode, parse_connection, |connection| connections.push(connection))?;
+
+        let lines = connections.len() + skipped.len();
+        let circuit = with_wire_a(connections.into_iter().collect(), lines)?;
+
+        Ok(Lenient::new(circuit, skipped))
+    }
+}
+
+/// Checks that wire a will have a value once the given circuit is emulated, since both parts of
+/// the puzzle ask for it.
+fn with_wire_a(circuit: Circuit, lines: usize) -> Result<Circuit, ParseError> {
+    if !circuit.inputs_by_wire.contains_key("a") {
+        return Err(ParseError::at_end(ErrorKind::MissingWire(String::from("a")), lines));
+    }
+
+    if !circuit.can_resolve("a") {
+        return Err(ParseError::at_end(ErrorKind::UnresolvedWire(String::from("a")), lines));
+    }
+
+    Ok(circuit)
+}
+
+fn wire_a_value(circuit: &mut Circuit) -> u16 {
+    *circuit.emulate().get("a").expect("Wire a should have a value")
+}
+
+#[derive(Clone, Debug)]
+pub struct Circuit {
+    inputs_by_wire: HashMap<String, Input>,
+    values_by_wire: HashMap<String, u16>,
+}
+
+impl Circuit {
+    pub fn from_lines(lines: impl Iterator<Item = String>) -> Result<Self, ParseError> {
+        lines.enumerate()
+            .map(|(i, line)| parse_connection(&line).map_err(|error| error.at_line(i + 1)))
+            .collect()
+    }
+
+    /// Replaces the input to the given wire and resets the values of all wires in the circuit.
+    pub fn set_input(&mut self, wire: &str, input: Input) {
+        self.inputs_by_wire.insert(String::from(wire), input);
+        self.values_by_wire.clear();
+    }
+
+    pub fn emulate(&mut self) -> HashMap<String, u16> {
+        // Iteratively resolve wires until we've got them all
+        while self.values_by_wire.len() < self.inputs_by_wire.len() {
+            let resolved_wires = self.values_by_wire.len();
+
+            for wire in self.inputs_by_wire.keys() {
+                if !self.values_by_wire.contains_key(wire) {
+                    if let Ok(value) = self.resolve_wire(wire) {
+                        self.values_by_wire.insert(wire.clone(), value);
+                    }
+                }
+            }
+
+            // If we couldn't resolve any new wires, the remaining wires depend (directly or
+            // indirectly) on wires with no input, and we'll never be able to resolve them
+            if self.values_by_wire.len() == resolved_wires {
+                break;
+            }
+        }
+
+        self.values_by_wire.clone()
+    }
+
+    /// Checks whether the given wire will have a value once the circuit is emulated, which is the
+    /// case if every wire it depends on (directly or indirectly) has an input and none of them
+    /// depend on themselves. Unlike emulating the circuit, this doesn't evaluate any gates.
+    fn can_resolve(&self, wire: &str) -> bool {
+        let mut resolvable: HashSet<&str> = HashSet::new();
+        let mut in_progress: HashSet<&str> = HashSet::new();
+
+        // Walk dependencies depth-first without recursion, since circuits can have long chains
+        // of wires; each wire is revisited once all of its dependencies have been checked
+        let mut stack = vec![(wire, false)];
+
+        while let Some((wire, dependencies_checked)) = stack.pop() {
+            if dependencies_checked {
+                in_progress.remove(wire);
+                resolvable.insert(wire);
+                continue;
+            }
+
+            if resolvable.contains(wire) {
+                continue;
+            }
+
+            // Meeting a wire that's still being checked means it depends on itself
+            if !in_progress.insert(wire) {
+                return false;
+            }
+
+            let Some(input) = self.inputs_by_wire.get(wire) else {
+                return false;
+            };
+
+            stack.push((wire, true));
+            stack.extend(input.wires()
+                .filter(|dependency| !resolvable.contains(dependency))
+                .map(|dependency| (dependency, false)));
+        }
+
+        true
+    }
+
+    fn resolve_wire(&self, wire: &str) -> Result<u16, ()> {
+        let input = self.inputs_by_wire.get(wire).expect("Wire should have input");
+
+        let value = match input {
+            Input::DirectSource(source) => self.resolve_source(source)?,
+            Input::Not(source) => !self.resolve_source(source)?,
+            Input::And(a, b) => self.resolve_source(a)? & self.resolve_source(b)?,
+            Input::Or(a, b) => self.resolve_source(a)? | self.resolve_source(b)?,
+            Input::LeftShift(source, bits) => self.resolve_source(source)? << bits,
+            Input::RightShift(source, bits) => self.resolve_source(source)? >> bits,
+        };
+
+        Ok(value)
+    }
+
+    fn resolve_source(&self, source: &Source) -> Result<u16, ()> {
+        match source {
+            Source::Signal(value) => Ok(*value),
+            Source::Wire(wire) => self.values_by_wire.get(wire).copied().ok_or(()),
+        }
+    }
+}
+
+impl FromIterator<(String, Input)> for Circuit {
+    fn from_iter<T: IntoIterator<Item = (String, Input)>>(connections: T) -> Self {
+        Circuit { inputs_by_wire: connections.into_iter().collect(), values_by_wire: HashMap::new() }
+    }
+}
+
+fn parse_connection(line: &str) -> Result<(String, Input), ParseError> {
+    if let [input, wire] = line.split(" -> ").collect::<Vec<&str>>().as_slice() {
+        let input = Input::from_str(input)
+            .map_err(|error| error.within(line, input))?;
+
+        Ok((String::from(*wire), input))
+    } else {
+        Err(ParseError::new(ErrorKind::MalformedLine, line, line))
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Input {
+    DirectSource(Source),
+    Not(Source),
+    And(Source, Source),
+    Or(Source, Source),
+    LeftShift(Source, u8),
+    RightShift(Source, u8),
+}
+
+impl Input {
+    /// Returns the wires (as opposed to signals) that feed into this input.
+    fn wires(&self) -> impl Iterator<Item = &str> {
+        let sources = match self {
+            Input::DirectSource(source) | Input::Not(source) |
+            Input::LeftShift(source, _) | Input::RightShift(source, _) => [Some(source), None],
+            Input::And(a, b) | Input::Or(a, b) => [Some(a), Some(b)],
+        };
+
+        sources.into_iter().flatten().filter_map(|source| match source {
+            Source::Signal(_) => None,
+            Source::Wire(wire) => Some(wire.as_str()),
+        })
+    }
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let pieces: Vec<&str> = string.split(' ').collect();
+
+        let source = |token: &str| Source::from_str(token)
+            .map_err(|error| error.within(string, token));
+
+        // Signals are 16 bits wide, so shifting by 16 or more bits would overflow
+        let bits = |token: &str| token.parse::<u8>().ok()
+            .filter(|&bits| bits < 16)
+            .ok_or_else(|| ParseError::new(ErrorKind::InvalidShift, string, token));
+
+        match pieces.as_slice() {
+            [a] => Ok(Input::DirectSource(source(a)?)),
+            ["NOT", a] => Ok(Input::Not(source(a)?)),
+            [a, "AND", b] => Ok(Input::And(source(a)?, source(b)?)),
+            [a, "OR", b] => Ok(Input::Or(source(a)?, source(b)?)),
+            [a, "LSHIFT", shift] => Ok(Input::LeftShift(source(a)?, bits(shift)?)),
+            [a, "RSHIFT", shift] => Ok(Input::RightShift(source(a)?, bits(shift)?)),
+            _ => Err(ParseError::new(ErrorKind::UnrecognizedGate, string, string))
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Source {
+    Signal(u16),
+    Wire(String),
+}
+
+impl FromStr for Source {
+    type Err = ParseError;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        if string.chars().all(|c| c.is_numeric()) {
+            Ok(Source::Signal(string.parse()
+                .map_err(|_| ParseError::new(ErrorKind::InvalidSignal, string, string))?))
+        } else if string.chars().all(|c| c.is_ascii_lowercase()) {
+            Ok(Source::Wire(String::from(string)))
+        } else {
+            Err(ParseError::new(ErrorKind::InvalidSource, string, string))
+        }
+    }
+}
+
+pub type ParseError = error::ParseError<ErrorKind>;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ErrorKind {
+    MalformedLine,
+    UnrecognizedGate,
+    InvalidSource,
+    InvalidSignal,
+    InvalidShift,
+    MissingWire(String),
+    UnresolvedWire(String),
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ErrorKind::MalformedLine => write!(f, "Could not parse line"),
+            ErrorKind::UnrecognizedGate => write!(f, "Unrecognized gate"),
+            ErrorKind::InvalidSource => write!(f, "Invalid source"),
+            ErrorKind::InvalidSignal => write!(f, "Invalid signal"),
+            ErrorKind::InvalidShift => write!(f, "Invalid shift"),
+            ErrorKind::MissingWire(wire) => write!(f, "No input to wire {}", wire),
+            ErrorKind::UnresolvedWire(wire) => write!(f, "Wire {} depends on a loop or a wire with no input", wire),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use proptest::prelude::*;
+    use indoc::indoc;
+
+    const TEST_CIRCUIT: &str = indoc! {"
+        123 -> x
+        456 -> y
+        x AND y -> d
+        x OR y -> e
+        x LSHIFT 2 -> f
+        y RSHIFT 2 -> g
+        NOT x -> h
+        NOT y -> i
+    "};
+
+    #[test]
+    fn test_emulate_circuit() {
+        let expected: HashMap<String, u16> = HashMap::from([
+            (String::from("d"), 72),
+            (String::from("e"), 507),
+            (String::from("f"), 492),
+            (String::from("g"), 114),
+            (String::from("h"), 65412),
+            (String::from("i"), 65079),
+            (String::from("x"), 123),
+            (String::from("y"), 456),
+        ]);
+
+        assert_eq!(
+            expected,
+            Circuit::from_lines(TEST_CIRCUIT.lines().map(String::from)).unwrap().emulate()
+        );
+    }
+
+    #[test]
+    fn test_circuit_from_lines_error() {
+        let error = Circuit::from_lines(indoc! {"
+            123 -> x
+            x AND 7y -> d
+        "}.lines().map(String::from)).err().unwrap();
+
+        assert_eq!(ErrorKind::InvalidSource, error.kind);
+        assert_eq!(2, error.line);
+        assert_eq!(7, error.column);
+        assert_eq!("7y", error.text);
+    }
+
+    #[test]
+    fn test_invalid_shift() {
+        let error = Day07::parse("1 -> b\nb LSHIFT 20 -> a\n").unwrap_err().downcast::<ParseError>().unwrap();
+
+        assert_eq!(ErrorKind::InvalidShift, error.kind);
+        assert_eq!(2, error.line);
+        assert_eq!(10, error.column);
+        assert_eq!("20", error.text);
+
+        assert_eq!(ErrorKind::InvalidShift, Input::from_str("b RSHIFT 16").unwrap_err().kind);
+        assert_eq!(Input::RightShift(Source::Wire(String::from("b")), 15), Input::from_str("b RSHIFT 15").unwrap());
+    }
+
+    #[test]
+    fn test_parse_requires_wire_a() {
+        let error = Day07::parse("").unwrap_err().downcast::<ParseError>().unwrap();
+        assert_eq!(ErrorKind::MissingWire(String::from("a")), error.kind);
+
+        let error = Day07::parse(TEST_CIRCUIT).unwrap_err().downcast::<ParseError>().unwrap();
+        assert_eq!(ErrorKind::MissingWire(String::from("a")), error.kind);
+        assert_eq!(9, error.line);
+
+        let error = Day07::parse("b -> a\nc -> b\n").unwrap_err().downcast::<ParseError>().unwrap();
+        assert_eq!(ErrorKind::UnresolvedWire(String::from("a")), error.kind);
+
+        let error = Day07::parse("1 -> c\nc AND b -> a\na -> b\n").unwrap_err().downcast::<ParseError>().unwrap();
+        assert_eq!(ErrorKind::UnresolvedWire(String::from("a")), error.kind);
+
+        // Wires that several gates depend on aren't mistaken for loops
+        let circuit = Day07::parse("3 -> x\nx AND x -> y\nx OR y -> z\ny LSHIFT 2 -> w\nz OR w -> a\n").unwrap();
+        assert_eq!(Answer::from(15u16), Day07::part_one(&circuit));
+
+        // Skipping the only line that drives wire b leaves wire a without a value
+        let error = Day07::parse_lenient("1 -> x\nx AND y -> b\nb ANDX 1 -> y\nb -> a\n").unwrap_err()
+            .downcast::<ParseError>()
+            .unwrap();
+        assert_eq!(ErrorKind::UnresolvedWire(String::from("a")), error.kind);
+
+        let Lenient { input, skipped } = Day07::parse_lenient("12 -> b\nb ANDX 1 -> y\nb -> a\n").unwrap();
+        assert_eq!(Answer::from(12u16), Day07::part_one(&input));
+        assert_eq!(1, skipped.len());
+    }
+
+    proptest! {
+        #[test]
+        fn test_emulate_direct_signals(signals in prop::collection::hash_map("[a-z]{1,3}", any::<u16>(), 0..20)) {
+            let input: String = signals.iter()
+                .map(|(wire, signal)| format!("{} -> {}\n", signal, wire))
+                .collect();
+
+            prop_assert_eq!(signals, Circuit::from_lines(input.lines().map(String::from)).unwrap().emulate());
+        }
+
+        #[test]
+        fn test_parse_does_not_panic(input in "\\PC*") {
+            let _ = Day07::parse(&input);
+        }
+
+        #[test]
+        fn test_can_resolve_matches_emulation(lines in prop::collection::vec("([0-9]{1,3}|[a-d]|NOT [a-d]|[a-d] (AND|OR) [a-d0-9]) -> [a-d]", 0..8)) {
+            let circuit: Circuit = lines.iter().map(|line| parse_connection(line).unwrap()).collect();
+
+            for wire in ["a", "b", "c", "d"] {
+                prop_assert_eq!(circuit.clone().emulate().contains_key(wire), circuit.can_resolve(wire));
+            }
+        }
+
+        #[test]
+        fn test_lenient_parse_is_solvable(lines in prop::collection::vec("([0-9]{1,3}|[ab]|[ab] (AND|OR|ANDX) [ab0-9]) -> [ab]", 0..6)) {
+            if let Ok(Lenient { input, .. }) = Day07::parse_lenient(&lines.join("\n")) {
+                Day07::part_one(&input);
+                Day07::part_two(&input);
+            }
+        }
+    }
+}
@@ -0,0 +1,264 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io::BufRead;
+use crate::{error, stream, Answer, Lenient, ParseMode, Part, Solution, Streamed, Streaming};
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, Box<dyn Error>> {
+        Ok(error::parse_lines(input, ParseMode::Strict, parse_literal)?.0)
+    }
+
+    fn parse_lenient(input: &str) -> Result<Lenient<Self::Input>, Box<dyn Error>> {
+        let (strings, skipped) = error::parse_lines(input, ParseMode::Lenient, parse_literal)?;
+        Ok(Lenient::new(strings, skipped))
+    }
+
+    fn part_one(strings: &Self::Input) -> Answer {
+        strings.iter()
+            .map(|string| overheads(string).expect("Strings are checked when parsing").0)
+            .sum::<usize>()
+            .into()
+    }
+
+    fn part_two(strings: &Self::Input) -> Option<Answer> {
+        Some(strings.iter()
+            .map(|string| overheads(string).expect("Strings are checked when parsing").1)
+            .sum::<usize>()
+            .into())
+    }
+}
+
+impl Streaming for Day08 {
+    fn solve_streaming(input: &mut dyn BufRead, part: Option<Part>, mode: ParseMode) -> Result<Streamed, Box<dyn Error>> {
+        let mut unescaped_overhead: usize = 0;
+        let mut escaped_overhead: usize = 0;
+
+        let skipped = stream::parse_lines(input, mode, overheads, |(unescaped, escaped)| {
+            unescaped_overhead += unescaped;
+            escaped_overhead += escaped;
+        })?;
+
+        Ok(Streamed {
+            part_one: Part::One.is_selected(part).then(|| unescaped_overhead.into()),
+            part_two: Part::Two.is_selected(part).then(|| escaped_overhead.into()),
+            skipped: error::boxed(skipped),
+        })
+    }
+}
+
+fn parse_literal(line: &str) -> Result<String, ParseError> {
+    parse_escaped_string(line)?;
+    Ok(String::from(line))
+}
+
+/// Returns how many more characters the given string literal takes in code than in memory, and
+/// how many more it would take in code if it were escaped again.
+fn overheads(string: &str) -> Result<(usize, usize), ParseError> {
+    let code = code_characters(string);
+    Ok((code - memory_characters(string)?, escaped_characters(string) - code))
+}
+
+pub fn code_characters(string: &str) -> usize {
+    string.chars().count()
+}
+
+pub fn memory_characters(string: &str) -> Result<usize, ParseError> {
+    Ok(parse_escaped_string(string)?.chars().count())
+}
+
+pub fn escaped_characters(string: &str) -> usize {
+    escape(string).chars().count()
+}
+
+/// Parses a double-quoted string literal made up of printable ASCII characters, where `\\`, `\"`,
+/// and `\x` followed by two hexadecimal digits are escape sequences.
+pub fn parse_escaped_string(escaped_string: &str) -> Result<String, ParseError> {
+    let contents = escaped_string.strip_prefix('"')
+        .and_then(|contents| contents.strip_suffix('"'))
+        .ok_or_else(|| ParseError::new(ErrorKind::MissingQuotes, escaped_string, escaped_string))?;
+
+    // Everything before `i` is ASCII, so `i` is always at a character boundary
+    let error = |kind: ErrorKind, i: usize, chars: usize| {
+        let end = contents[i..].char_indices().nth(chars).map_or(contents.len(), |(len, _)| i + len);
+        ParseError::new(kind, escaped_string, &contents[i..end])
+    };
+
+    let bytes = contents.as_bytes();
+    let mut string = String::with_capacity(bytes.len());
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let (c, len) = match bytes[i] {
+            b'\\' => match bytes.get(i + 1) {
+                Some(b'\\') => ('\\', 2),
+                Some(b'"') => ('"', 2),
+                Some(b'x') => match bytes.get(i + 2..i + 4) {
+                    Some(hex) if hex.iter().all(u8::is_ascii_hexdigit) => {
+                        (u8::from_str_radix(&contents[i + 2..i + 4], 16).unwrap() as char, 4)
+                    },
+                    _ => return Err(error(ErrorKind::InvalidEscape, i, 4)),
+                },
+                _ => return Err(error(ErrorKind::InvalidEscape, i, 2)),
+            },
+            b'"' => return Err(error(ErrorKind::UnescapedQuote, i, 1)),
+            b' '..=b'~' => (bytes[i] as char, 1),
+            _ => return Err(error(ErrorKind::UnexpectedCharacter(contents[i..].chars().next().unwrap()), i, 1)),
+        };
+
+        string.push(c);
+        i += len;
+    }
+
+    Ok(string)
+}
+
+pub fn escape(string: &str) -> String {
+    let mut escaped_string = String::from('"');
+
+    string
+        .chars()
+        .for_each(|c| match c {
+            '\\' => escaped_string.push_str(r#"\\"#),
+            '"' => escaped_string.push_str(r#"\""#),
+            _ => escaped_string.push(c),
+        });
+
+    escaped_string.push('"');
+
+    escaped_string
+}
+
+pub type ParseError = error::ParseError<ErrorKind>;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ErrorKind {
+    MissingQuotes,
+    UnescapedQuote,
+    InvalidEscape,
+    UnexpectedCharacter(char),
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ErrorKind::MissingQuotes => write!(f, "String must start and end with a double quote"),
+            ErrorKind::UnescapedQuote => write!(f, "Unescaped double quote"),
+            ErrorKind::InvalidEscape => write!(f, "Invalid escape sequence"),
+            ErrorKind::UnexpectedCharacter(c) => write!(f, "Unexpected character {:?}", c),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_code_characters() {
+        assert_eq!(2, code_characters(r#""""#));
+        assert_eq!(5, code_characters(r#""abc""#));
+        assert_eq!(10, code_characters(r#""aaa\"aaa""#));
+        assert_eq!(6, code_characters(r#""\x27""#));
+    }
+
+    #[test]
+    fn test_memory_characters() {
+        assert_eq!(Ok(0), memory_characters(r#""""#));
+        assert_eq!(Ok(3), memory_characters(r#""abc""#));
+        assert_eq!(Ok(7), memory_characters(r#""aaa\"aaa""#));
+        assert_eq!(Ok(1), memory_characters(r#""\x27""#));
+    }
+
+    #[test]
+    fn test_parse_escaped_string() {
+        assert_eq!(Ok(String::from("")), parse_escaped_string(r#""""#));
+        assert_eq!(Ok(String::from("abc")), parse_escaped_string(r#""abc""#));
+        assert_eq!(Ok(String::from("aaa\"aaa")), parse_escaped_string(r#""aaa\"aaa""#));
+        assert_eq!(Ok(String::from("'")), parse_escaped_string(r#""\x27""#));
+    }
+
+    #[test]
+    fn test_parse_escaped_string_error() {
+        let error = |string: &str| {
+            let error = parse_escaped_string(string).unwrap_err();
+            (error.kind, error.column, error.text)
+        };
+
+        assert_eq!((ErrorKind::MissingQuotes, 1, String::from("")), error(""));
+        assert_eq!((ErrorKind::MissingQuotes, 1, String::from("\"")), error("\""));
+        assert_eq!((ErrorKind::MissingQuotes, 1, String::from("\"abc")), error("\"abc"));
+        assert_eq!((ErrorKind::InvalidEscape, 3, String::from("\\q")), error(r#""a\q""#));
+        assert_eq!((ErrorKind::InvalidEscape, 2, String::from("\\x4")), error(r#""\x4""#));
+        assert_eq!((ErrorKind::InvalidEscape, 2, String::from("\\x+f")), error(r#""\x+f""#));
+        assert_eq!((ErrorKind::InvalidEscape, 2, String::from("\\é")), error(r#""\é""#));
+        assert_eq!((ErrorKind::InvalidEscape, 2, String::from("\\")), error(r#""\""#));
+        assert_eq!((ErrorKind::UnescapedQuote, 3, String::from("\"")), error(r#""a"b""#));
+        assert_eq!((ErrorKind::UnexpectedCharacter('é'), 2, String::from("é")), error(r#""é""#));
+    }
+
+    #[test]
+    fn test_parse() {
+        let error = Day08::parse("\"abc\"\n\n").unwrap_err().downcast::<ParseError>().unwrap();
+        assert_eq!(ErrorKind::MissingQuotes, error.kind);
+        assert_eq!(2, error.line);
+
+        let Lenient { input, skipped } = Day08::parse_lenient("\"abc\"\n\n\"\\q\"\n\"\\x27\"").unwrap();
+        assert_eq!(vec![String::from("\"abc\""), String::from("\"\\x27\"")], input);
+        assert_eq!(2, skipped.len());
+        assert_eq!(Answer::from(7usize), Day08::part_one(&input));
+    }
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(r#""\"\"""#, escape(r#""""#));
+        assert_eq!(r#""\"abc\"""#, escape(r#""abc""#));
+        assert_eq!(r#""\"aaa\\\"aaa\"""#, escape(r#""aaa\"aaa""#));
+        assert_eq!(r#""\"\\x27\"""#, escape(r#""\x27""#));
+    }
+
+    proptest! {
+        #[test]
+        fn test_escape_round_trip(string in "[ -~]*") {
+            prop_assert_eq!(Ok(string.clone()), parse_escaped_string(&escape(&string)));
+        }
+
+        #[test]
+        fn test_escaped_characters_longer_than_code_characters(string in "\"[ -~]*\"") {
+            prop_assert!(escaped_characters(&string) >= code_characters(&string) + 4);
+            prop_assert!(memory_characters(&escape(&string)) == Ok(code_characters(&string)));
+        }
+
+        #[test]
+        fn test_parse_escaped_string_does_not_panic(string in "\\PC*") {
+            let _ = parse_escaped_string(&string);
+        }
+
+        #[test]
+        fn test_malformed_lines_are_skipped(lines in prop::collection::vec(r#""?([a\\"é]|\\x[0-9a-g]{0,2})*"?"#, 0..10)) {
+            let input = lines.join("\n");
+            let lines: Vec<&str> = input.lines().collect();
+
+            let valid_lines: Vec<&&str> = lines.iter()
+                .filter(|line| parse_escaped_string(line).is_ok())
+                .collect();
+
+            let Lenient { input: strings, skipped } = Day08::parse_lenient(&input).unwrap();
+            prop_assert_eq!(valid_lines.len(), strings.len());
+            prop_assert_eq!(lines.len() - valid_lines.len(), skipped.len());
+            prop_assert_eq!(skipped.is_empty(), Day08::parse(&input).is_ok());
+
+            let streamed = Day08::solve_streaming(&mut input.as_bytes(), None, ParseMode::Lenient).unwrap();
+            prop_assert_eq!(Some(Day08::part_one(&strings)), streamed.part_one);
+            prop_assert_eq!(Day08::part_two(&strings), streamed.part_two);
+
+            for string in strings {
+                prop_assert!(memory_characters(&string).unwrap() <= code_characters(&string));
+            }
+        }
+    }
+}
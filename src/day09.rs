@@ -0,0 +1,288 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io::BufRead;
+use std::str::FromStr;
+use itertools::Itertools;
+use crate::{error, stream, Answer, Lenient, ParseMode, Solution, StreamingParse};
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Distances;
+
+    fn parse(input: &str) -> Result<Self::Input, Box<dyn Error>> {
+        Ok(Distances::from_str(input)?)
+    }
+
+    fn parse_lenient(input: &str) -> Result<Lenient<Self::Input>, Box<dyn Error>> {
+        let (entries, skipped) = error::parse_lines(input, ParseMode::Lenient, parse_entry)?;
+        let distances = Distances::complete(entries, input.lines().count())?;
+
+        Ok(Lenient::new(distances, skipped))
+    }
+
+    fn part_one(distances: &Self::Input) -> Answer {
+        let route = distances.optimal_route();
+
+        Answer::from(distances.route_length(route.as_slice()))
+            .with_detail("route", route)
+    }
+
+    fn part_two(distances: &Self::Input) -> Option<Answer> {
+        let route = distances.worst_route();
+
+        Some(Answer::from(distances.route_length(route.as_slice()))
+            .with_detail("route", route))
+    }
+}
+
+impl StreamingParse for Day09 {
+    fn parse_streaming(input: &mut dyn BufRead, mode: ParseMode) -> Result<Lenient<Self::Input>, Box<dyn Error>> {
+        let mut entries = Vec::new();
+        let skipped = stream::parse_lines(input, mode, parse_entry, |entry| entries.push(entry))?;
+
+        let lines = entries.len() + skipped.len();
+        let distances = Distances::complete(entries, lines)?;
+
+        Ok(Lenient::new(distances, skipped))
+    }
+}
+
+#[derive(Debug)]
+pub struct Distances {
+    distances: HashMap<String, HashMap<String, u32>>,
+}
+
+impl FromStr for Distances {
+    type Err = ParseError;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let (entries, _) = error::parse_lines(string, ParseMode::Strict, parse_entry)?;
+        Distances::complete(entries, string.lines().count())
+    }
+}
+
+impl Distances {
+    /// Collects the given entries, which were parsed from the given number of lines, and checks
+    /// that there's a distance between every pair of locations so every route has a length.
+    fn complete(entries: Vec<(String, String, u32)>, lines: usize) -> Result<Self, ParseError> {
+        let distances: Distances = entries.into_iter().collect();
+
+        if distances.distances.is_empty() {
+            return Err(ParseError::at_end(ErrorKind::NoLocations, lines));
+        }
+
+        for (a, b) in distances.locations().sorted().tuple_combinations() {
+            if !distances.distances[a].contains_key(b) {
+                return Err(ParseError::at_end(ErrorKind::MissingDistance(a.to_string(), b.to_string()), lines));
+            }
+        }
+
+        Ok(distances)
+    }
+}
+
+impl FromIterator<(String, String, u32)> for Distances {
+    fn from_iter<T: IntoIterator<Item = (String, String, u32)>>(entries: T) -> Self {
+        let mut distances = HashMap::new();
+
+        for (a, b, distance) in entries {
+            distances.entry(a.clone()).or_insert_with(HashMap::new)
+                .insert(b.clone(), distance);
+
+            distances.entry(b).or_insert_with(HashMap::new)
+                .insert(a, distance);
+        }
+
+        Distances { distances }
+    }
+}
+
+fn parse_entry(line: &str) -> Result<(String, String, u32), ParseError> {
+    if let [destinations, distance] = line.split(" = ").collect::<Vec<&str>>().as_slice() {
+        let distance = distance.parse()
+            .map_err(|_| ParseError::new(ErrorKind::InvalidDistance, line, distance))?;
+
+        if let [a, b] = destinations.split(" to ").collect::<Vec<&str>>().as_slice() {
+            Ok((a.to_string(), b.to_string(), distance))
+        } else {
+            Err(ParseError::new(ErrorKind::MalformedLocationPair, line, destinations))
+        }
+    } else {
+        Err(ParseError::new(ErrorKind::MalformedEntry, line, line))
+    }
+}
+
+pub type ParseError = error::ParseError<ErrorKind>;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ErrorKind {
+    MalformedEntry,
+    MalformedLocationPair,
+    InvalidDistance,
+    NoLocations,
+    MissingDistance(String, String),
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ErrorKind::MalformedEntry => write!(f, "Could not parse entry"),
+            ErrorKind::MalformedLocationPair => write!(f, "Could not parse location pair"),
+            ErrorKind::InvalidDistance => write!(f, "Invalid distance"),
+            ErrorKind::NoLocations => write!(f, "No locations"),
+            ErrorKind::MissingDistance(a, b) => write!(f, "No distance between {} and {}", a, b),
+        }
+    }
+}
+
+impl Distances {
+    pub fn locations(&self) -> impl Iterator<Item = &str> {
+        self.distances.keys().map(|location| location.as_str())
+    }
+
+    pub fn optimal_route(&self) -> Vec<&str> {
+        self.locations()
+            .permutations(self.distances.len())
+            .min_by_key(|route| self.route_length(route))
+            .unwrap()
+            .into_iter()
+            .collect()
+    }
+
+    pub fn worst_route(&self) -> Vec<&str> {
+        self.locations()
+            .permutations(self.distances.len())
+            .max_by_key(|route| self.route_length(route))
+            .unwrap()
+            .into_iter()
+            .collect()
+    }
+
+    /// Returns the total length of the given route, which is widened so that adding up the
+    /// distances between locations can't overflow.
+    pub fn route_length(&self, route: &[&str]) -> u64 {
+        route.windows(2)
+            .map(|pair| u64::from(self.distance(pair[0], pair[1])))
+            .sum()
+    }
+
+    pub fn distance(&self, a: &str, b: &str) -> u32 {
+        *self.distances.get(a).unwrap().get(b).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use proptest::prelude::*;
+    use indoc::indoc;
+
+    const TEST_LOCATIONS: &str = indoc!{"
+        London to Dublin = 464
+        London to Belfast = 518
+        Dublin to Belfast = 141
+    "};
+
+    #[test]
+    fn test_optimal_route() {
+        let distances = Distances::from_str(TEST_LOCATIONS).unwrap();
+
+        assert_eq!(605, distances.route_length(distances.optimal_route().as_slice()))
+    }
+
+    #[test]
+    fn test_route_length_does_not_overflow() {
+        let distances = Distances::from_str("A to B = 4294967295\nB to C = 4294967295\nA to C = 1\n").unwrap();
+
+        assert_eq!(2 * u32::MAX as u64, distances.route_length(distances.worst_route().as_slice()));
+    }
+
+    #[test]
+    fn test_worst_route() {
+        let distances = Distances::from_str(TEST_LOCATIONS).unwrap();
+
+        assert_eq!(982, distances.route_length(distances.worst_route().as_slice()))
+    }
+
+    #[test]
+    fn test_distances_from_str_error() {
+        let error = Distances::from_str(indoc! {"
+            London to Dublin = 464
+            London to Belfast = far
+        "}).err().unwrap();
+
+        assert_eq!(ErrorKind::InvalidDistance, error.kind);
+        assert_eq!(2, error.line);
+        assert_eq!(21, error.column);
+        assert_eq!("far", error.text);
+    }
+
+    #[test]
+    fn test_distances_from_str_incomplete() {
+        let error = Distances::from_str("").err().unwrap();
+        assert_eq!(ErrorKind::NoLocations, error.kind);
+
+        let error = Distances::from_str(indoc! {"
+            London to Dublin = 464
+            London to Belfast = 518
+        "}).err().unwrap();
+
+        assert_eq!(ErrorKind::MissingDistance(String::from("Belfast"), String::from("Dublin")), error.kind);
+        assert_eq!(3, error.line);
+
+        // Skipping a malformed line leaves a pair of locations without a distance
+        let error = Day09::parse_lenient(indoc! {"
+            London to Dublin = 464
+            London to Belfast = 518
+            Dublin to Belfast = far
+        "}).err().unwrap().downcast::<ParseError>().unwrap();
+
+        assert_eq!(ErrorKind::MissingDistance(String::from("Belfast"), String::from("Dublin")), error.kind);
+    }
+
+    fn distances() -> impl Strategy<Value = Distances> {
+        (2..6usize)
+            .prop_flat_map(|locations| prop::collection::vec(1..1000u32, locations * (locations - 1) / 2)
+                .prop_map(move |distances| (locations, distances)))
+            .prop_map(|(locations, distances)| (0..locations)
+                .tuple_combinations()
+                .zip(distances)
+                .map(|((a, b), distance)| (format!("L{}", a), format!("L{}", b), distance))
+                .collect())
+    }
+
+    proptest! {
+        #[test]
+        fn test_reversed_route_length(distances in distances()) {
+            let route: Vec<&str> = distances.locations().collect();
+            let reversed: Vec<&str> = route.iter().rev().copied().collect();
+
+            prop_assert_eq!(distances.route_length(&route), distances.route_length(&reversed));
+        }
+
+        #[test]
+        fn test_optimal_route_no_longer_than_worst_route(distances in distances()) {
+            let optimal = distances.route_length(&distances.optimal_route());
+            let worst = distances.route_length(&distances.worst_route());
+            let route: Vec<&str> = distances.locations().collect();
+
+            prop_assert!(optimal <= distances.route_length(&route));
+            prop_assert!(distances.route_length(&route) <= worst);
+        }
+
+        #[test]
+        fn test_parse_does_not_panic(input in "\\PC*") {
+            let _ = Day09::parse(&input);
+        }
+
+        #[test]
+        fn test_lenient_parse_is_solvable(lines in prop::collection::vec("[A-C] to [A-C] = [0-9x]{1,2}", 0..6)) {
+            if let Ok(Lenient { input, .. }) = Day09::parse_lenient(&lines.join("\n")) {
+                Day09::part_one(&input);
+                Day09::part_two(&input);
+            }
+        }
+    }
+}
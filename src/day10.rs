@@ -0,0 +1,74 @@
+use std::error::Error;
+use itertools::Itertools;
+use crate::{Answer, Solution};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input, Box<dyn Error>> {
+        Ok(String::from(input.trim()))
+    }
+
+    fn part_one(seed: &Self::Input) -> Answer {
+        evolve_repeatedly(seed, 40).len().into()
+    }
+
+    fn part_two(seed: &Self::Input) -> Option<Answer> {
+        Some(evolve_repeatedly(seed, 50).len().into())
+    }
+}
+
+pub fn evolve_repeatedly(seed: &str, iterations: usize) -> String {
+    let mut evolved = String::from(seed);
+
+    for _ in 0..iterations {
+        evolved = evolve(&evolved);
+    }
+
+    evolved
+}
+
+pub fn evolve(string: &str) -> String {
+    let mut evolved = String::new();
+
+    for (char, group) in &string.chars().group_by(|c| *c) {
+        evolved.push_str(format!("{}", group.count()).as_str());
+        evolved.push(char);
+    }
+
+    evolved
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_evolve() {
+        assert_eq!("11", evolve("1"));
+        assert_eq!("21", evolve("11"));
+        assert_eq!("1211", evolve("21"));
+        assert_eq!("111221", evolve("1211"));
+        assert_eq!("312211", evolve("111221"));
+    }
+
+    proptest! {
+        #[test]
+        fn test_evolve_preserves_digit_count(seed in "[1-3]{1,50}") {
+            // Runs of ten or more digits would have multi-digit counts
+            prop_assume!(seed.chars().group_by(|c| *c).into_iter().all(|(_, run)| run.count() < 10));
+
+            let evolved = evolve(&seed);
+            let counted: usize = evolved.as_bytes()
+                .chunks(2)
+                .map(|pair| (pair[0] - b'0') as usize)
+                .sum();
+
+            prop_assert_eq!(0, evolved.len() % 2);
+            prop_assert_eq!(seed.len(), counted);
+        }
+    }
+}
@@ -1,29 +1,25 @@
 use std::error::Error;
+use std::fmt::{Display, Formatter};
 use std::ops::AddAssign;
 use std::str::FromStr;
 use itertools::Itertools;
+use crate::{error, Answer, Solution};
 
-fn main() -> Result<(), Box<dyn Error>> {
-    let args: Vec<String> = std::env::args().collect();
+pub struct Day11;
 
-    if let Some(initial_password) = args.get(1) {
-        let password = Password::from_str(initial_password)?;
+impl Solution for Day11 {
+    type Input = Password;
 
-        println!(
-            "Next valid password after {}: {}",
-            initial_password,
-            password.next_valid_password().to_string()
-        );
+    fn parse(input: &str) -> Result<Self::Input, Box<dyn Error>> {
+        Ok(Password::from_str(input.trim())?)
+    }
 
-        println!(
-            "Next valid password after {}: {}",
-            password.next_valid_password().to_string(),
-            password.next_valid_password().next_valid_password().to_string()
-        );
+    fn part_one(password: &Self::Input) -> Answer {
+        password.next_valid_password().to_string().into()
+    }
 
-        Ok(())
-    } else {
-        Err("Usage: day11 INITIAL_PASSWORD".into())
+    fn part_two(password: &Self::Input) -> Option<Answer> {
+        Some(password.next_valid_password().next_valid_password().to_string().into())
     }
 }
 
@@ -33,36 +29,55 @@ const ALPHABET: [char; 23] = [
 ];
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
-struct Password {
+pub struct Password {
     password: u64,
     min_string_length: usize,
 }
 
 impl FromStr for Password {
-    type Err = Box<dyn Error>;
+    type Err = ParseError;
 
     fn from_str(string: &str) -> Result<Self, Self::Err> {
-        let mut password = 0;
+        let mut password: u64 = 0;
 
-        for c in string.chars() {
+        for (i, c) in string.char_indices() {
             let char_val = match c {
                 'a'..='h' => c as u64 - 'a' as u64,
                 'j' | 'k' => c as u64 - 'a' as u64 - 1,
                 'm' | 'n' => c as u64 - 'a' as u64 - 2,
                 'p'..='z' => c as u64 - 'a' as u64 - 3,
-                _ => return Err("Unexpected character".into()),
+                _ => return Err(ParseError::new(
+                    ErrorKind::UnexpectedCharacter(c), string, &string[i..i + c.len_utf8()])),
             };
 
-            password = (password * ALPHABET.len() as u64) + char_val;
+            password = password.checked_mul(ALPHABET.len() as u64)
+                .and_then(|password| password.checked_add(char_val))
+                .ok_or_else(|| ParseError::new(ErrorKind::TooLong, string, &string[i..]))?;
         }
 
         Ok(Password { password, min_string_length: string.chars().count() })
     }
 }
 
-impl ToString for Password {
-    fn to_string(&self) -> String {
+pub type ParseError = error::ParseError<ErrorKind>;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ErrorKind {
+    UnexpectedCharacter(char),
+    TooLong,
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ErrorKind::UnexpectedCharacter(c) => write!(f, "Unexpected character {:?}", c),
+            ErrorKind::TooLong => write!(f, "Password too long"),
+        }
+    }
+}
 
+impl Display for Password {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let mut reverse = String::new();
         let mut password = self.password;
 
@@ -80,7 +95,7 @@ impl ToString for Password {
             reverse.push(ALPHABET[0]);
         }
 
-        reverse.chars().rev().collect()
+        write!(f, "{}", reverse.chars().rev().collect::<String>())
     }
 }
 
@@ -92,7 +107,7 @@ impl AddAssign<u64> for Password {
 
 impl Password {
 
-    fn next_valid_password(&self) -> Self {
+    pub fn next_valid_password(&self) -> Self {
         let mut next = *self;
 
         loop {
@@ -104,7 +119,7 @@ impl Password {
         }
     }
 
-    fn is_valid(&self) -> bool {
+    pub fn is_valid(&self) -> bool {
         let password_str = self.to_string();
 
         // Because our alphabet excludes illegal characters, we can assume passwords never contain
@@ -143,11 +158,18 @@ impl Password {
 #[cfg(test)]
 mod test {
     use super::*;
+    use proptest::prelude::*;
 
     #[test]
     fn test_to_from_string() {
         let password_string = "xz";
         assert_eq!(password_string, Password::from_str(password_string).unwrap().to_string());
+
+        let error = Password::from_str("abcDef").unwrap_err();
+        assert_eq!(ErrorKind::UnexpectedCharacter('D'), error.kind);
+        assert_eq!(4, error.column);
+
+        assert_eq!(ErrorKind::TooLong, Password::from_str("zzzzzzzzzzzzzzzz").unwrap_err().kind);
     }
 
     #[test]
@@ -196,4 +218,19 @@ mod test {
             Password::from_str("abcdefgh").unwrap().next_valid_password()
         );
     }
-}
\ No newline at end of file
+
+    proptest! {
+        #[test]
+        fn test_password_string_round_trip(string in "[a-hjkmnp-z]{1,13}") {
+            let password = Password::from_str(&string).unwrap();
+
+            prop_assert_eq!(&string, &password.to_string());
+            prop_assert_eq!(password, Password::from_str(&password.to_string()).unwrap());
+        }
+
+        #[test]
+        fn test_parse_does_not_panic(input in "\\PC*") {
+            let _ = Day11::parse(&input);
+        }
+    }
+}
@@ -0,0 +1,173 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io::BufRead;
+use crate::{error, stream, Answer, ParseMode, Part, Solution, Streamed, Streaming};
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    /// The sum of every number in the document, which is checked for overflow while parsing
+    type Input = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, Box<dyn Error>> {
+        Ok(sum_of_numbers(input)?)
+    }
+
+    fn part_one(sum: &Self::Input) -> Answer {
+        (*sum).into()
+    }
+}
+
+impl Streaming for Day12 {
+    fn solve_streaming(input: &mut dyn BufRead, part: Option<Part>, _mode: ParseMode) -> Result<Streamed, Box<dyn Error>> {
+        let mut sum = NumberSum::default();
+        stream::for_each_char(input, |_, c| -> Result<(), Box<dyn Error>> { Ok(sum.push(c)?) })?;
+
+        let part_one = if Part::One.is_selected(part) { Some(sum.total()?.into()) } else { None };
+
+        Ok(Streamed { part_one, part_two: None, skipped: Vec::new() })
+    }
+}
+
+pub fn sum_of_numbers(json: &str) -> Result<i64, ParseError> {
+    let mut sum = NumberSum::default();
+    json.chars().try_for_each(|c| sum.push(c))?;
+
+    sum.total()
+}
+
+/// A running sum of the (possibly negative) integers in text that's read one character at a time.
+#[derive(Default)]
+struct NumberSum {
+    sum: i64,
+    current: Option<i64>,
+    negative: bool,
+    /// The number of complete lines read so far
+    lines: usize,
+    /// The 0-based column of the next character within the current line
+    column: usize,
+    /// The 0-based column at which the current number (including its sign) begins
+    start: usize,
+}
+
+impl NumberSum {
+    fn push(&mut self, c: char) -> Result<(), ParseError> {
+        if let Some(digit) = c.to_digit(10) {
+            let digit = digit as i64;
+
+            if self.current.is_none() {
+                self.start = self.column - self.negative as usize;
+            }
+
+            let partial = self.current.unwrap_or(0);
+            let current = partial.checked_mul(10);
+
+            // Accumulate negative numbers as negative so we can represent i64::MIN
+            let current = if self.negative {
+                current.and_then(|current| current.checked_sub(digit))
+            } else {
+                current.and_then(|current| current.checked_add(digit))
+            };
+
+            match current {
+                Some(current) => self.current = Some(current),
+                None => {
+                    let sign = if self.negative && partial == 0 { "-" } else { "" };
+                    return Err(self.number_too_large(format!("{}{}{}", sign, partial, digit)));
+                }
+            }
+        } else {
+            self.finish_number()?;
+            self.negative = c == '-';
+        }
+
+        if c == '\n' {
+            self.lines += 1;
+            self.column = 0;
+        } else {
+            self.column += 1;
+        }
+
+        Ok(())
+    }
+
+    fn finish_number(&mut self) -> Result<(), ParseError> {
+        if let Some(current) = self.current.take() {
+            self.sum = self.sum.checked_add(current)
+                .ok_or_else(|| self.number_too_large(current.to_string()))?;
+        }
+
+        Ok(())
+    }
+
+    fn total(mut self) -> Result<i64, ParseError> {
+        self.finish_number()?;
+        Ok(self.sum)
+    }
+
+    /// Returns an error for the current number, which either doesn't fit in an i64 itself or
+    /// makes the sum overflow, and which begins with the given text.
+    fn number_too_large(&self, text: String) -> ParseError {
+        ParseError { kind: ErrorKind::NumberTooLarge, line: self.lines + 1, column: self.start + 1, text }
+    }
+}
+
+pub type ParseError = error::ParseError<ErrorKind>;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ErrorKind {
+    NumberTooLarge,
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ErrorKind::NumberTooLarge => write!(f, "Number too large"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_sum_of_numbers() {
+        assert_eq!(6, sum_of_numbers(r#"[1,2,3]"#).unwrap());
+        assert_eq!(6, sum_of_numbers(r#"{"a":2,"b":4}"#).unwrap());
+        assert_eq!(3, sum_of_numbers(r#"[[[3]]]"#).unwrap());
+        assert_eq!(3, sum_of_numbers(r#"{"a":{"b":4},"c":-1}"#).unwrap());
+        assert_eq!(0, sum_of_numbers(r#"{"a":[-1,1]}"#).unwrap());
+        assert_eq!(0, sum_of_numbers(r#"[-1,{"a":1}]"#).unwrap());
+        assert_eq!(0, sum_of_numbers(r#"[]"#).unwrap());
+        assert_eq!(0, sum_of_numbers(r#"{}"#).unwrap());
+    }
+
+    #[test]
+    fn test_number_too_large() {
+        let error = Day12::parse("[1,99999999999999999999]").unwrap_err().downcast::<ParseError>().unwrap();
+
+        assert_eq!(ErrorKind::NumberTooLarge, error.kind);
+        assert_eq!(1, error.line);
+        assert_eq!(4, error.column);
+        assert_eq!("9999999999999999999", error.text);
+
+        let error = sum_of_numbers("[\n  9223372036854775807,\n  -1,\n  2\n]").unwrap_err();
+        assert_eq!((4, 3, String::from("2")), (error.line, error.column, error.text));
+
+        let error = sum_of_numbers("[-9223372036854775809]").unwrap_err();
+        assert_eq!((1, 2), (error.line, error.column));
+
+        assert_eq!(i64::MIN, sum_of_numbers("[-9223372036854775808]").unwrap());
+    }
+
+    proptest! {
+        #[test]
+        fn test_sum_of_numbers_in_array(numbers in prop::collection::vec(-1_000_000..1_000_000i64, 0..50)) {
+            let json = serde_json::to_string(&numbers).unwrap();
+
+            prop_assert_eq!(numbers.iter().sum::<i64>(), sum_of_numbers(&json).unwrap());
+        }
+    }
+}
@@ -0,0 +1,321 @@
+use std::collections::{BTreeSet, HashMap};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io::BufRead;
+use std::str::FromStr;
+use itertools::Itertools;
+use lazy_static::lazy_static;
+use regex::Regex;
+use crate::{error, stream, Answer, Lenient, ParseMode, Solution, StreamingParse};
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = SeatingArrangement;
+
+    fn parse(input: &str) -> Result<Self::Input, Box<dyn Error>> {
+        Ok(SeatingArrangement::from_str(input)?)
+    }
+
+    fn parse_lenient(input: &str) -> Result<Lenient<Self::Input>, Box<dyn Error>> {
+        let (rules, skipped) = error::parse_lines(input, ParseMode::Lenient, parse_rule)?;
+        let seating_arrangement = SeatingArrangement::complete(rules, input.lines().count())?;
+
+        Ok(Lenient::new(seating_arrangement, skipped))
+    }
+
+    fn part_one(seating_arrangement: &Self::Input) -> Answer {
+        optimal_seating(seating_arrangement)
+    }
+
+    fn part_two(seating_arrangement: &Self::Input) -> Option<Answer> {
+        let mut seating_arrangement = seating_arrangement.clone();
+        seating_arrangement.add_host();
+
+        Some(optimal_seating(&seating_arrangement))
+    }
+}
+
+impl StreamingParse for Day13 {
+    fn parse_streaming(input: &mut dyn BufRead, mode: ParseMode) -> Result<Lenient<Self::Input>, Box<dyn Error>> {
+        let mut rules = Vec::new();
+        let skipped = stream::parse_lines(input, mode, parse_rule, |rule| rules.push(rule))?;
+
+        let lines = rules.len() + skipped.len();
+        let seating_arrangement = SeatingArrangement::complete(rules, lines)?;
+
+        Ok(Lenient::new(seating_arrangement, skipped))
+    }
+}
+
+fn optimal_seating(seating_arrangement: &SeatingArrangement) -> Answer {
+    let arrangement = seating_arrangement.optimal_arrangement();
+
+    Answer::from(seating_arrangement.change_in_happiness(arrangement.as_slice()))
+        .with_detail("seating_order", arrangement)
+}
+
+#[derive(Clone, Debug)]
+pub struct SeatingArrangement {
+    happiness_changes: HashMap<String, HashMap<String, i32>>,
+}
+
+impl FromStr for SeatingArrangement {
+    type Err = ParseError;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let (rules, _) = error::parse_lines(string, ParseMode::Strict, parse_rule)?;
+        SeatingArrangement::complete(rules, string.lines().count())
+    }
+}
+
+impl SeatingArrangement {
+    /// Collects the given rules, which were parsed from the given number of lines, and checks that
+    /// there are at least two guests and a rule for each guest sitting next to every other guest.
+    fn complete(rules: Vec<(String, String, i32)>, lines: usize) -> Result<Self, ParseError> {
+        let guests: BTreeSet<String> = rules.iter()
+            .flat_map(|(subject, neighbor, _)| [subject.clone(), neighbor.clone()])
+            .collect();
+
+        let seating_arrangement: SeatingArrangement = rules.into_iter().collect();
+
+        if guests.len() < 2 {
+            return Err(ParseError::at_end(ErrorKind::TooFewGuests, lines));
+        }
+
+        for (subject, neighbor) in guests.iter().tuple_combinations().flat_map(|(a, b)| [(a, b), (b, a)]) {
+            let has_rule = seating_arrangement.happiness_changes.get(subject)
+                .is_some_and(|changes| changes.contains_key(neighbor));
+
+            if !has_rule {
+                return Err(ParseError::at_end(ErrorKind::MissingRule(subject.clone(), neighbor.clone()), lines));
+            }
+        }
+
+        Ok(seating_arrangement)
+    }
+}
+
+impl FromIterator<(String, String, i32)> for SeatingArrangement {
+    fn from_iter<T: IntoIterator<Item = (String, String, i32)>>(rules: T) -> Self {
+        let mut happiness_changes: HashMap<String, HashMap<String, i32>> = HashMap::new();
+
+        for (subject, neighbor, happiness_change) in rules {
+            happiness_changes.entry(subject)
+                .or_default()
+                .insert(neighbor, happiness_change);
+        }
+
+        SeatingArrangement { happiness_changes }
+    }
+}
+
+fn parse_rule(line: &str) -> Result<(String, String, i32), ParseError> {
+    lazy_static! {
+        static ref RULE_PATTERN: Regex =
+            Regex::new("([a-zA-Z]+) would (gain|lose) ([0-9]+) happiness units by sitting next to ([a-zA-Z]+).").unwrap();
+    }
+
+    if let Some(captures) = RULE_PATTERN.captures(line) {
+        let subject = captures[1].to_string();
+        let is_gain = &captures[2] == "gain";
+        let magnitude: i32 = captures[3].parse()
+            .map_err(|_| ParseError::new(ErrorKind::InvalidMagnitude, line, &captures[3]))?;
+        let neighbor = captures[4].to_string();
+
+        Ok((subject, neighbor, if is_gain {
+            magnitude
+        } else {
+            -magnitude
+        }))
+    } else {
+        Err(ParseError::new(ErrorKind::MalformedRule, line, line))
+    }
+}
+
+pub type ParseError = error::ParseError<ErrorKind>;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ErrorKind {
+    MalformedRule,
+    InvalidMagnitude,
+    TooFewGuests,
+    MissingRule(String, String),
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ErrorKind::MalformedRule => write!(f, "Rule string did not match expected pattern"),
+            ErrorKind::InvalidMagnitude => write!(f, "Invalid happiness magnitude"),
+            ErrorKind::TooFewGuests => write!(f, "Need at least two guests"),
+            ErrorKind::MissingRule(subject, neighbor) => write!(f, "No rule for {} sitting next to {}", subject, neighbor),
+        }
+    }
+}
+
+impl SeatingArrangement {
+    pub fn guests(&self) -> impl Iterator<Item = &str> {
+        self.happiness_changes.keys().map(|guest| guest.as_str())
+    }
+
+    pub fn optimal_arrangement(&self) -> Vec<&str> {
+        self.guests()
+            .permutations(self.happiness_changes.len())
+            .max_by_key(|arrangement| self.change_in_happiness(arrangement))
+            .unwrap()
+    }
+
+    /// Returns the total change in happiness for the given arrangement, which is widened so that
+    /// adding up every guest's change in happiness can't overflow.
+    pub fn change_in_happiness(&self, arrangement: &[&str]) -> i64 {
+        assert!(arrangement.len() > 1);
+
+        let change = |subject: &str, neighbor: &str| -> i64 {
+            (*self.happiness_changes.get(subject).unwrap().get(neighbor).unwrap()).into()
+        };
+
+        let mut happiness_change = 0;
+
+        // Treat the first entry as a special case to deal with negative wrapping
+        happiness_change += change(arrangement[0], arrangement[1]);
+        happiness_change += change(arrangement[0], arrangement[arrangement.len() - 1]);
+
+
+        for i in 1..arrangement.len() {
+            happiness_change += change(arrangement[i], arrangement[(i + 1) % arrangement.len()]);
+            happiness_change += change(arrangement[i], arrangement[i - 1]);
+        }
+
+        happiness_change
+    }
+
+    pub fn add_host(&mut self) {
+        const HOST: &str = "Host";
+
+        let guests: Vec<String> = self.guests().map(String::from).collect();
+
+        for guest in guests {
+            let guest = guest.to_string();
+
+            self.happiness_changes.entry(HOST.to_string()).or_default().insert(guest.clone(), 0);
+            self.happiness_changes.entry(guest).or_default().insert(HOST.to_string(), 0);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use proptest::prelude::*;
+    use indoc::indoc;
+
+    const TEST_RULES: &str = indoc!{"
+        Alice would gain 54 happiness units by sitting next to Bob.
+        Alice would lose 79 happiness units by sitting next to Carol.
+        Alice would lose 2 happiness units by sitting next to David.
+        Bob would gain 83 happiness units by sitting next to Alice.
+        Bob would lose 7 happiness units by sitting next to Carol.
+        Bob would lose 63 happiness units by sitting next to David.
+        Carol would lose 62 happiness units by sitting next to Alice.
+        Carol would gain 60 happiness units by sitting next to Bob.
+        Carol would gain 55 happiness units by sitting next to David.
+        David would gain 46 happiness units by sitting next to Alice.
+        David would lose 7 happiness units by sitting next to Bob.
+        David would gain 41 happiness units by sitting next to Carol.
+    "};
+
+    #[test]
+    fn test_optimal_arrangement() {
+        let seating_arrangement = SeatingArrangement::from_str(TEST_RULES).unwrap();
+        assert_eq!(330, seating_arrangement.change_in_happiness(seating_arrangement.optimal_arrangement().as_slice()));
+    }
+
+    #[test]
+    fn test_change_in_happiness_does_not_overflow() {
+        let seating_arrangement = SeatingArrangement::from_str(indoc! {"
+            Alice would gain 2147483647 happiness units by sitting next to Bob.
+            Bob would gain 2147483647 happiness units by sitting next to Alice.
+        "}).unwrap();
+
+        assert_eq!(4 * i32::MAX as i64, seating_arrangement.change_in_happiness(&["Alice", "Bob"]));
+    }
+
+    #[test]
+    fn test_seating_arrangement_from_str_error() {
+        let error = SeatingArrangement::from_str(indoc! {"
+            Alice would gain 54 happiness units by sitting next to Bob.
+            Bob would gain 99999999999 happiness units by sitting next to Alice.
+        "}).err().unwrap();
+
+        assert_eq!(ErrorKind::InvalidMagnitude, error.kind);
+        assert_eq!(2, error.line);
+        assert_eq!(16, error.column);
+    }
+
+    #[test]
+    fn test_seating_arrangement_from_str_incomplete() {
+        let error = SeatingArrangement::from_str("").err().unwrap();
+        assert_eq!(ErrorKind::TooFewGuests, error.kind);
+        assert_eq!(1, error.line);
+
+        let error = SeatingArrangement::from_str(indoc! {"
+            Alice would gain 54 happiness units by sitting next to Bob.
+        "}).err().unwrap();
+
+        assert_eq!(ErrorKind::MissingRule(String::from("Bob"), String::from("Alice")), error.kind);
+        assert_eq!(2, error.line);
+
+        // Skipping a malformed line leaves a guest without a rule for one of their neighbors
+        let error = Day13::parse_lenient(&TEST_RULES.replace("gain 46", "gain lots")).err().unwrap()
+            .downcast::<ParseError>()
+            .unwrap();
+
+        assert_eq!(ErrorKind::MissingRule(String::from("David"), String::from("Alice")), error.kind);
+    }
+
+    fn seating_arrangement() -> impl Strategy<Value = SeatingArrangement> {
+        (3..6usize)
+            .prop_flat_map(|guests| prop::collection::vec(-100..100i32, guests * (guests - 1))
+                .prop_map(move |changes| (guests, changes)))
+            .prop_map(|(guests, changes)| (0..guests)
+                .permutations(2)
+                .zip(changes)
+                .map(|(pair, change)| (format!("G{}", pair[0]), format!("G{}", pair[1]), change))
+                .collect())
+    }
+
+    proptest! {
+        #[test]
+        fn test_change_in_happiness_is_rotation_and_reflection_invariant(
+            seating_arrangement in seating_arrangement(),
+            rotation in 0..6usize,
+        ) {
+            let arrangement: Vec<&str> = seating_arrangement.guests().collect();
+            let mut rotated = arrangement.clone();
+            rotated.rotate_left(rotation % arrangement.len());
+            let reversed: Vec<&str> = arrangement.iter().rev().copied().collect();
+
+            let change = seating_arrangement.change_in_happiness(&arrangement);
+
+            prop_assert_eq!(change, seating_arrangement.change_in_happiness(&rotated));
+            prop_assert_eq!(change, seating_arrangement.change_in_happiness(&reversed));
+            prop_assert!(change <= seating_arrangement.change_in_happiness(&seating_arrangement.optimal_arrangement()));
+        }
+
+        #[test]
+        fn test_parse_does_not_panic(input in "\\PC*") {
+            let _ = Day13::parse(&input);
+        }
+
+        #[test]
+        fn test_lenient_parse_is_solvable(
+            lines in prop::collection::vec("[A-C] would (gain|lose) [0-9x]{1,2} happiness units by sitting next to [A-C].", 0..8),
+        ) {
+            if let Ok(Lenient { input, .. }) = Day13::parse_lenient(&lines.join("\n")) {
+                Day13::part_one(&input);
+                Day13::part_two(&input);
+            }
+        }
+    }
+}
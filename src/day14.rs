@@ -0,0 +1,225 @@
+use std::cmp;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io::BufRead;
+use std::str::FromStr;
+use regex::Regex;
+use crate::{error, stream, Answer, Lenient, ParseMode, Solution, StreamingParse};
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Vec<Reindeer>;
+
+    fn parse(input: &str) -> Result<Self::Input, Box<dyn Error>> {
+        let (reindeer, _) = error::parse_lines(input, ParseMode::Strict, Reindeer::from_str)?;
+        Ok(at_least_one_reindeer(reindeer, input.lines().count())?)
+    }
+
+    fn parse_lenient(input: &str) -> Result<Lenient<Self::Input>, Box<dyn Error>> {
+        let (reindeer, skipped) = error::parse_lines(input, ParseMode::Lenient, Reindeer::from_str)?;
+        let reindeer = at_least_one_reindeer(reindeer, input.lines().count())?;
+
+        Ok(Lenient::new(reindeer, skipped))
+    }
+
+    fn part_one(reindeer: &Self::Input) -> Answer {
+        let winner = winning_reindeer(reindeer, RACE_DURATION);
+
+        Answer::from(winner.distance_traveled(RACE_DURATION))
+            .with_detail("winner", winner.name.as_str())
+    }
+}
+
+impl StreamingParse for Day14 {
+    fn parse_streaming(input: &mut dyn BufRead, mode: ParseMode) -> Result<Lenient<Self::Input>, Box<dyn Error>> {
+        let mut reindeer = Vec::new();
+        let skipped = stream::parse_lines(input, mode, Reindeer::from_str, |parsed| reindeer.push(parsed))?;
+
+        let lines = reindeer.len() + skipped.len();
+        let reindeer = at_least_one_reindeer(reindeer, lines)?;
+
+        Ok(Lenient::new(reindeer, skipped))
+    }
+}
+
+/// Checks that there's at least one reindeer in the race, since there's otherwise no winner.
+fn at_least_one_reindeer(reindeer: Vec<Reindeer>, lines: usize) -> Result<Vec<Reindeer>, ParseError> {
+    if reindeer.is_empty() {
+        Err(ParseError::at_end(ErrorKind::NoReindeer, lines))
+    } else {
+        Ok(reindeer)
+    }
+}
+
+pub const RACE_DURATION: u32 = 2503;
+
+pub fn winning_reindeer(reindeer: &[Reindeer], time: u32) -> &Reindeer {
+    reindeer.iter()
+        .max_by_key(|reindeer| reindeer.distance_traveled(time))
+        .unwrap()
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Reindeer {
+    pub name: String,
+    pub velocity: u32,
+    pub fly_time: u32,
+    pub rest_time: u32,
+}
+
+impl FromStr for Reindeer {
+    type Err = ParseError;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let pattern =
+            Regex::new("([A-Za-z]+) can fly ([0-9]+) km/s for ([0-9]+) seconds, but then must rest for ([0-9]+) seconds.").unwrap();
+
+        if let Some(captures) = pattern.captures(string) {
+            let number = |i: usize| -> Result<u32, ParseError> {
+                captures[i].parse()
+                    .map_err(|_| ParseError::new(ErrorKind::InvalidNumber, string, &captures[i]))
+            };
+
+            let name = captures[1].to_string();
+            let velocity = number(2)?;
+            let fly_time = number(3)?;
+            let rest_time = number(4)?;
+
+            if fly_time == 0 && rest_time == 0 {
+                return Err(ParseError::new(ErrorKind::ZeroCycleTime, string, string));
+            }
+
+            Ok(Reindeer { name, velocity, fly_time, rest_time })
+        } else {
+            Err(ParseError::new(ErrorKind::MalformedLine, string, string))
+        }
+    }
+}
+
+pub type ParseError = error::ParseError<ErrorKind>;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ErrorKind {
+    MalformedLine,
+    InvalidNumber,
+    ZeroCycleTime,
+    NoReindeer,
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ErrorKind::MalformedLine => write!(f, "Could not parse line"),
+            ErrorKind::InvalidNumber => write!(f, "Invalid number"),
+            ErrorKind::ZeroCycleTime => write!(f, "Reindeer must fly or rest for a nonzero time"),
+            ErrorKind::NoReindeer => write!(f, "No reindeer"),
+        }
+    }
+}
+
+impl Reindeer {
+    /// Returns the distance traveled after the given time, which is widened so that neither the
+    /// cycle time nor the distance can overflow.
+    pub fn distance_traveled(&self, time: u32) -> u64 {
+        let (time, fly_time) = (u64::from(time), u64::from(self.fly_time));
+
+        let cycle_time = fly_time + u64::from(self.rest_time);
+        let full_cycles = time / cycle_time;
+        let travel_time_in_last_cycle = cmp::min(time % cycle_time, fly_time);
+
+        ((fly_time * full_cycles) + travel_time_in_last_cycle) * u64::from(self.velocity)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use proptest::prelude::*;
+    use indoc::indoc;
+
+    #[test]
+    fn test_distance_traveled() {
+        let comet = Reindeer::from_str("Comet can fly 14 km/s for 10 seconds, but then must rest for 127 seconds.").unwrap();
+        let dancer = Reindeer::from_str("Dancer can fly 16 km/s for 11 seconds, but then must rest for 162 seconds.").unwrap();
+
+        assert_eq!(14, comet.distance_traveled(1));
+        assert_eq!(16, dancer.distance_traveled(1));
+
+        assert_eq!(140, comet.distance_traveled(10));
+        assert_eq!(160, dancer.distance_traveled(10));
+
+        assert_eq!(140, comet.distance_traveled(11));
+        assert_eq!(176, dancer.distance_traveled(11));
+
+        assert_eq!(140, comet.distance_traveled(12));
+        assert_eq!(176, dancer.distance_traveled(12));
+
+        assert_eq!(1120, comet.distance_traveled(1000));
+        assert_eq!(1056, dancer.distance_traveled(1000));
+    }
+
+    #[test]
+    fn test_distance_traveled_does_not_overflow() {
+        let reindeer = Reindeer::from_str(
+            "Comet can fly 4294967295 km/s for 10 seconds, but then must rest for 4294967295 seconds."
+        ).unwrap();
+
+        assert_eq!(10 * u32::MAX as u64, reindeer.distance_traveled(RACE_DURATION));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = Day14::parse(indoc! {"
+            Comet can fly 14 km/s for 10 seconds, but then must rest for 127 seconds.
+            Dancer can fly 16 km/s for 11 seconds, but then must rest for a while.
+        "}).unwrap_err().downcast::<ParseError>().unwrap();
+
+        assert_eq!(ErrorKind::MalformedLine, error.kind);
+        assert_eq!(2, error.line);
+
+        let error = Day14::parse("").unwrap_err().downcast::<ParseError>().unwrap();
+        assert_eq!(ErrorKind::NoReindeer, error.kind);
+
+        let error = Day14::parse_lenient("Dancer can fly 16 km/s for 11 seconds, but then must rest for a while.\n")
+            .unwrap_err()
+            .downcast::<ParseError>()
+            .unwrap();
+
+        assert_eq!(ErrorKind::NoReindeer, error.kind);
+        assert_eq!(2, error.line);
+    }
+
+    #[test]
+    fn test_part_one() {
+        let reindeer = Day14::parse(indoc! {"
+            Comet can fly 14 km/s for 10 seconds, but then must rest for 127 seconds.
+            Dancer can fly 16 km/s for 11 seconds, but then must rest for 162 seconds.
+        "}).unwrap();
+
+        assert_eq!(
+            Answer::from(2660u32).with_detail("winner", "Comet"),
+            Day14::part_one(&reindeer)
+        );
+    }
+
+    fn reindeer() -> impl Strategy<Value = Reindeer> {
+        (0..100u32, 0..100u32, 0..100u32)
+            .prop_filter("Reindeer must fly or rest", |(_, fly_time, rest_time)| fly_time + rest_time > 0)
+            .prop_map(|(velocity, fly_time, rest_time)|
+                Reindeer { name: String::from("Rudolph"), velocity, fly_time, rest_time })
+    }
+
+    proptest! {
+        #[test]
+        fn test_distance_traveled_is_monotonic(reindeer in reindeer(), time in 0..5000u32) {
+            prop_assert!(reindeer.distance_traveled(time) <= reindeer.distance_traveled(time + 1));
+            prop_assert!(reindeer.distance_traveled(time + 1) - reindeer.distance_traveled(time) <= reindeer.velocity as u64);
+        }
+
+        #[test]
+        fn test_parse_does_not_panic(input in "\\PC*") {
+            let _ = Day14::parse(&input);
+        }
+    }
+}
@@ -1,40 +1,69 @@
 use std::cmp::max;
 use std::collections::HashMap;
 use std::error::Error;
-use std::fs;
+use std::fmt::{Display, Formatter};
+use std::io::BufRead;
 use std::iter::Sum;
 use std::ops::{Add, Mul};
 use std::str::FromStr;
+use crate::{error, stream, Answer, Lenient, ParseMode, Solution, StreamingParse};
 
-fn main() -> Result<(), Box<dyn Error>> {
-    let args: Vec<String> = std::env::args().collect();
+pub struct Day15;
 
-    if let Some(path) = args.get(1) {
-        let recipe = Recipe::from_str(fs::read_to_string(path)?.as_str())?;
+impl Solution for Day15 {
+    type Input = Recipe;
 
-        println!(
-            "Optimal recipe score: {}",
-            recipe.optimize_ingredients(None)
-        );
+    fn parse(input: &str) -> Result<Self::Input, Box<dyn Error>> {
+        Ok(Recipe::from_str(input)?)
+    }
 
-        println!(
-            "Optimal recipe score with 500 calorie target: {}",
-            recipe.optimize_ingredients(Some(500))
-        );
+    fn parse_lenient(input: &str) -> Result<Lenient<Self::Input>, Box<dyn Error>> {
+        let (ingredients, skipped) = error::parse_lines(input, ParseMode::Lenient, parse_ingredient)?;
+        let recipe = Recipe::complete(ingredients, input.lines().count())?;
 
-        Ok(())
-    } else {
-        Err("Usage: day15 INPUT_FILE_PATH".into())
+        Ok(Lenient::new(recipe, skipped))
+    }
+
+    fn part_one(recipe: &Self::Input) -> Answer {
+        recipe.optimize_ingredients(None).into()
+    }
+
+    fn part_two(recipe: &Self::Input) -> Option<Answer> {
+        Some(recipe.optimize_ingredients(Some(500)).into())
+    }
+}
+
+impl StreamingParse for Day15 {
+    fn parse_streaming(input: &mut dyn BufRead, mode: ParseMode) -> Result<Lenient<Self::Input>, Box<dyn Error>> {
+        let mut ingredients = Vec::new();
+        let skipped = stream::parse_lines(input, mode, parse_ingredient, |ingredient| ingredients.push(ingredient))?;
+
+        let lines = ingredients.len() + skipped.len();
+        let recipe = Recipe::complete(ingredients, lines)?;
+
+        Ok(Lenient::new(recipe, skipped))
     }
 }
 
 #[derive(Debug, Eq, PartialEq)]
-struct Recipe {
-    ingredients: HashMap<String, Properties>,
+pub struct Recipe {
+    pub ingredients: HashMap<String, Properties>,
 }
 
 impl Recipe {
-    fn optimize_ingredients(&self, target_calories: Option<i32>) -> u64 {
+    /// Collects the given ingredients, which were parsed from the given number of lines, and checks
+    /// that there's at least one ingredient to put in the recipe.
+    fn complete(ingredients: Vec<(String, Properties)>, lines: usize) -> Result<Self, ParseError> {
+        if ingredients.is_empty() {
+            return Err(ParseError::at_end(ErrorKind::NoIngredients, lines));
+        }
+
+        Ok(Recipe {
+            ingredients: ingredients.into_iter().collect(),
+        })
+    }
+
+    pub fn optimize_ingredients(&self, target_calories: Option<i32>) -> u64 {
         let properties: Vec<&Properties> = self.ingredients.values().collect();
         let mut quantities = vec![0; properties.len()];
         let mut stack: Vec<(usize, i32)> = (0..=100).map(|amount| (0, amount)).collect();
@@ -104,35 +133,32 @@ impl Recipe {
 }
 
 impl FromStr for Recipe {
-    type Err = Box<dyn Error>;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let ingredients = s
-            .lines()
-            .map(|line| {
-                if let Some((name, properties)) = line.split_once(": ") {
-                    Ok((
-                        String::from(name),
-                        Properties::from_str(properties)
-                            .map_err(|_| "Could not parse properties")?,
-                    ))
-                } else {
-                    Err("Could not parse ingredient line")
-                }
-            })
-            .collect::<Result<HashMap<_, _>, _>>()?;
+        let (ingredients, _) = error::parse_lines(s, ParseMode::Strict, parse_ingredient)?;
+        Recipe::complete(ingredients, s.lines().count())
+    }
+}
 
-        Ok(Recipe { ingredients })
+fn parse_ingredient(line: &str) -> Result<(String, Properties), ParseError> {
+    if let Some((name, properties)) = line.split_once(": ") {
+        Ok((
+            String::from(name),
+            Properties::from_str(properties).map_err(|error| error.within(line, properties))?,
+        ))
+    } else {
+        Err(ParseError::new(ErrorKind::MalformedIngredient, line, line))
     }
 }
 
 #[derive(Debug, Eq, PartialEq, Copy, Clone, Default)]
-struct Properties {
-    capacity: i32,
-    durability: i32,
-    flavor: i32,
-    texture: i32,
-    calories: i32,
+pub struct Properties {
+    pub capacity: i32,
+    pub durability: i32,
+    pub flavor: i32,
+    pub texture: i32,
+    pub calories: i32,
 }
 
 impl Add for Properties {
@@ -176,7 +202,7 @@ impl Sum<Properties> for Properties {
 }
 
 impl FromStr for Properties {
-    type Err = Box<dyn Error>;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut capacity = None;
@@ -187,7 +213,9 @@ impl FromStr for Properties {
 
         for pair in s.split(", ") {
             if let Some((property, value)) = pair.split_once(' ') {
-                let value = value.parse::<i32>()?;
+                let value = value
+                    .parse::<i32>()
+                    .map_err(|_| ParseError::new(ErrorKind::InvalidValue, s, value))?;
 
                 match property {
                     "capacity" => capacity = Some(value),
@@ -195,28 +223,50 @@ impl FromStr for Properties {
                     "flavor" => flavor = Some(value),
                     "texture" => texture = Some(value),
                     "calories" => calories = Some(value),
-                    _ => return Err("Unexpected property".into()),
+                    _ => return Err(ParseError::new(ErrorKind::UnexpectedProperty, s, property)),
                 }
             } else {
-                return Err("Could not parse property pair".into());
+                return Err(ParseError::new(ErrorKind::MalformedProperty, s, pair));
             }
         }
 
-        if capacity.is_some()
-            && durability.is_some()
-            && flavor.is_some()
-            && texture.is_some()
-            && calories.is_some()
+        if let (Some(capacity), Some(durability), Some(flavor), Some(texture), Some(calories)) =
+            (capacity, durability, flavor, texture, calories)
         {
             Ok(Properties {
-                capacity: capacity.unwrap(),
-                durability: durability.unwrap(),
-                flavor: flavor.unwrap(),
-                texture: texture.unwrap(),
-                calories: calories.unwrap(),
+                capacity,
+                durability,
+                flavor,
+                texture,
+                calories,
             })
         } else {
-            Err("Missing one or more properties".into())
+            Err(ParseError::new(ErrorKind::MissingProperties, s, s))
+        }
+    }
+}
+
+pub type ParseError = error::ParseError<ErrorKind>;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ErrorKind {
+    MalformedIngredient,
+    MalformedProperty,
+    UnexpectedProperty,
+    InvalidValue,
+    MissingProperties,
+    NoIngredients,
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ErrorKind::MalformedIngredient => write!(f, "Could not parse ingredient line"),
+            ErrorKind::MalformedProperty => write!(f, "Could not parse property pair"),
+            ErrorKind::UnexpectedProperty => write!(f, "Unexpected property"),
+            ErrorKind::InvalidValue => write!(f, "Invalid property value"),
+            ErrorKind::MissingProperties => write!(f, "Missing one or more properties"),
+            ErrorKind::NoIngredients => write!(f, "No ingredients"),
         }
     }
 }
@@ -224,6 +274,7 @@ impl FromStr for Properties {
 #[cfg(test)]
 mod test {
     use super::*;
+    use proptest::prelude::*;
     use indoc::indoc;
 
     const TEST_RECIPE: &str = indoc! {"
@@ -261,6 +312,28 @@ mod test {
         assert_eq!(expected_recipe, Recipe::from_str(TEST_RECIPE).unwrap());
     }
 
+    #[test]
+    fn test_recipe_from_str_error() {
+        let error = Recipe::from_str(indoc! {"
+            Butterscotch: capacity -1, durability -2, flavor 6, texture 3, calories 8
+            Cinnamon: capacity 2, durability 3, flavor -2, sparkle -1, calories 3
+        "})
+        .unwrap_err();
+
+        assert_eq!(ErrorKind::UnexpectedProperty, error.kind);
+        assert_eq!(2, error.line);
+        assert_eq!(48, error.column);
+        assert_eq!("sparkle", error.text);
+
+        assert_eq!(ErrorKind::NoIngredients, Recipe::from_str("").unwrap_err().kind);
+
+        let error = Day15::parse_streaming(&mut "Sprinkles: capacity 2\n".as_bytes(), ParseMode::Lenient).unwrap_err()
+            .downcast::<ParseError>()
+            .unwrap();
+
+        assert_eq!(ErrorKind::NoIngredients, error.kind);
+    }
+
     #[test]
     fn test_recipe_optimize_ingredients() {
         assert_eq!(
@@ -280,4 +353,11 @@ mod test {
                 .optimize_ingredients(Some(500))
         );
     }
+
+    proptest! {
+        #[test]
+        fn test_parse_does_not_panic(input in "\\PC*") {
+            let _ = Day15::parse(&input);
+        }
+    }
 }
@@ -0,0 +1,175 @@
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+use crate::{Lenient, ParseMode};
+
+/// An error encountered while parsing puzzle input, along with the location of the offending text
+/// within the input. Each day with structured input defines its own `kind` type that describes the
+/// ways in which parsing that day's input can fail.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseError<K> {
+    pub kind: K,
+    /// The 1-based number of the line containing the offending text
+    pub line: usize,
+    /// The 1-based column at which the offending text begins
+    pub column: usize,
+    pub text: String,
+}
+
+impl<K> ParseError<K> {
+    /// Creates a new error for the given offending text, which is expected to be a slice of the
+    /// given line. The error initially refers to the first line of the input; callers parsing
+    /// multi-line input should use [`ParseError::at_line`] to attach the actual line number.
+    pub(crate) fn new(kind: K, line: &str, text: &str) -> Self {
+        ParseError {
+            kind,
+            line: 1,
+            column: offset_within(line, text) + 1,
+            text: String::from(text),
+        }
+    }
+
+    /// Creates a new error for input whose lines are each well-formed, but which is incomplete as
+    /// a whole (e.g. because it's empty). The error refers to the end of the given number of lines
+    /// of input.
+    pub(crate) fn at_end(kind: K, lines: usize) -> Self {
+        ParseError { kind, line: lines + 1, column: 1, text: String::new() }
+    }
+
+    pub(crate) fn at_line(mut self, line: usize) -> Self {
+        self.line = line;
+        self
+    }
+
+    /// Adjusts the column of an error produced while parsing the given fragment of a line so that
+    /// it refers to a position within the line as a whole.
+    pub(crate) fn within(mut self, line: &str, fragment: &str) -> Self {
+        self.column += offset_within(line, fragment);
+        self
+    }
+}
+
+fn offset_within(line: &str, fragment: &str) -> usize {
+    let line_start = line.as_ptr() as usize;
+    let fragment_start = fragment.as_ptr() as usize;
+
+    if fragment_start >= line_start && fragment_start + fragment.len() <= line_start + line.len() {
+        fragment_start - line_start
+    } else {
+        0
+    }
+}
+
+/// Parses each line of the given input with the given function, attaching line numbers to any
+/// errors. In strict mode, parsing stops at the first malformed line; in lenient mode, malformed
+/// lines are skipped and their errors are returned alongside the successfully-parsed lines.
+pub(crate) fn parse_lines<T, K>(
+    input: &str,
+    mode: ParseMode,
+    parse: impl Fn(&str) -> Result<T, ParseError<K>>,
+) -> Result<ParsedLines<T, K>, ParseError<K>> {
+    let (parsed, skipped) = parse_numbered_lines(input, mode, parse)?;
+    Ok((parsed.into_iter().map(|(_, item)| item).collect(), skipped))
+}
+
+/// Like [`parse_lines`], but pairs each successfully-parsed line with its 1-based line number so
+/// callers can refer back to the input after malformed lines have been skipped.
+pub(crate) fn parse_numbered_lines<T, K>(
+    input: &str,
+    mode: ParseMode,
+    parse: impl Fn(&str) -> Result<T, ParseError<K>>,
+) -> Result<ParsedLines<(usize, T), K>, ParseError<K>> {
+    let mut parsed = Vec::new();
+    let mut skipped = Vec::new();
+
+    for (i, line) in input.lines().enumerate() {
+        match parse(line) {
+            Ok(item) => parsed.push((i + 1, item)),
+            Err(error) => match mode {
+                ParseMode::Strict => return Err(error.at_line(i + 1)),
+                ParseMode::Lenient => skipped.push(error.at_line(i + 1)),
+            },
+        }
+    }
+
+    Ok((parsed, skipped))
+}
+
+pub(crate) type ParsedLines<T, K> = (Vec<T>, Vec<ParseError<K>>);
+
+impl<T> Lenient<T> {
+    pub(crate) fn new<K: Debug + Display + 'static>(input: T, skipped: Vec<ParseError<K>>) -> Self {
+        Lenient { input, skipped: boxed(skipped) }
+    }
+}
+
+pub(crate) fn boxed<K: Debug + Display + 'static>(errors: Vec<ParseError<K>>) -> Vec<Box<dyn Error>> {
+    errors.into_iter()
+        .map(|error| Box::new(error) as Box<dyn Error>)
+        .collect()
+}
+
+impl<K: Display> Display for ParseError<K> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} at line {}, column {}: {:?}", self.kind, self.line, self.column, self.text)
+    }
+}
+
+impl<K: Debug + Display> Error for ParseError<K> {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        let line = "turn on 0,0 through 999,999";
+        let error = ParseError::new("Bad coordinate", line, &line[20..27]).at_line(7);
+
+        assert_eq!(7, error.line);
+        assert_eq!(21, error.column);
+        assert_eq!("999,999", error.text);
+        assert_eq!("Bad coordinate at line 7, column 21: \"999,999\"", error.to_string());
+    }
+
+    #[test]
+    fn test_at_end() {
+        let error = ParseError::at_end("No entries", 3);
+
+        assert_eq!(4, error.line);
+        assert_eq!(1, error.column);
+        assert_eq!("No entries at line 4, column 1: \"\"", error.to_string());
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        let parse = |line: &str| line.parse::<u32>()
+            .map_err(|_| ParseError::new("Not a number", line, line));
+
+        assert_eq!((vec![1, 2, 3], vec![]), parse_lines("1\n2\n3\n", ParseMode::Strict, parse).unwrap());
+        assert_eq!(2, parse_lines("1\ntwo\n3\n", ParseMode::Strict, parse).unwrap_err().line);
+
+        let (parsed, skipped) = parse_lines("1\ntwo\n3\nfour", ParseMode::Lenient, parse).unwrap();
+        assert_eq!(vec![1, 3], parsed);
+        assert_eq!(vec![2, 4], skipped.iter().map(|error| error.line).collect::<Vec<usize>>());
+    }
+
+    #[test]
+    fn test_parse_numbered_lines() {
+        let parse = |line: &str| line.parse::<u32>()
+            .map_err(|_| ParseError::new("Not a number", line, line));
+
+        let (parsed, skipped) = parse_numbered_lines("1\ntwo\n3\n", ParseMode::Lenient, parse).unwrap();
+        assert_eq!(vec![(1, 1), (3, 3)], parsed);
+        assert_eq!(1, skipped.len());
+    }
+
+    #[test]
+    fn test_within() {
+        let line = "Sugar: capacity 3, durability x";
+        let fragment = &line[7..];
+        let error = ParseError::new("Bad value", fragment, &fragment[23..]).within(line, fragment);
+
+        assert_eq!(31, error.column);
+        assert_eq!("x", error.text);
+    }
+}
@@ -0,0 +1,403 @@
+use std::error::Error;
+use std::ops::RangeInclusive;
+
+/// Generates a synthetic puzzle input for the given day in the same text format as the real
+/// puzzle input. The meaning of `size` depends on the day (see [`size_description`]), and the
+/// same day, size, and seed always produce the same input.
+pub fn generate(day: u8, size: usize, seed: u64) -> Result<String, Box<dyn Error>> {
+    let mut rng = Rng::new(seed);
+
+    let (min_size, max_size) = match day {
+        4 | 11 => (1, 13),
+        7 | 13 | 15 => (2, usize::MAX),
+        1..=15 => (1, usize::MAX),
+        _ => return Err(format!("No generator for day {}", day).into()),
+    };
+
+    if size < min_size {
+        return Err(format!("Day {} inputs must have at least {} {}", day, min_size, size_description(day)).into());
+    } else if size > max_size {
+        return Err(format!("Day {} inputs must have at most {} {}", day, max_size, size_description(day)).into());
+    }
+
+    Ok(match day {
+        1 => directions(&mut rng, size),
+        2 => presents(&mut rng, size),
+        3 => moves(&mut rng, size),
+        4 => letters(&mut rng, size, "abcdefghijklmnopqrstuvwxyz"),
+        5 => strings(&mut rng, size),
+        6 => light_instructions(&mut rng, size),
+        7 => circuit(&mut rng, size),
+        8 => escaped_strings(&mut rng, size),
+        9 => distances(&mut rng, size),
+        10 => letters(&mut rng, size, "123"),
+        11 => letters(&mut rng, size, "abcdefghjkmnpqrstuvwxyz"),
+        12 => json_document(&mut rng, size),
+        13 => happiness_rules(&mut rng, size),
+        14 => reindeer(&mut rng, size),
+        15 => ingredients(&mut rng, size),
+        _ => unreachable!(),
+    })
+}
+
+/// Returns a size for each day's generated input that's similar to the size of the real puzzle
+/// input.
+pub fn default_size(day: u8) -> usize {
+    match day {
+        1 => 7000,
+        2 | 5 | 12 => 1000,
+        3 => 8192,
+        6 | 8 => 300,
+        7 => 340,
+        10 => 10,
+        14 => 9,
+        15 => 4,
+        _ => 8,
+    }
+}
+
+/// Describes what the size of each day's generated input measures.
+pub fn size_description(day: u8) -> &'static str {
+    match day {
+        1 | 3 => "directions",
+        2 => "presents",
+        4 => "characters in the key",
+        5 | 8 => "strings",
+        6 => "instructions",
+        7 => "wires",
+        9 => "locations",
+        10 => "digits in the seed",
+        11 => "characters in the password",
+        12 => "numbers",
+        13 => "guests",
+        14 => "reindeer",
+        15 => "ingredients",
+        _ => "items",
+    }
+}
+
+/// A small, fast pseudorandom number generator (SplitMix64). We use our own rather than an
+/// external crate so that a given seed produces the same inputs regardless of dependency
+/// versions.
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a number in the range `0..bound`.
+    pub fn below(&mut self, bound: usize) -> usize {
+        debug_assert!(bound > 0);
+        ((self.next_u64() as u128 * bound as u128) >> 64) as usize
+    }
+
+    pub fn range(&mut self, range: RangeInclusive<i32>) -> i32 {
+        range.start() + self.below((range.end() - range.start()) as usize + 1) as i32
+    }
+
+    pub fn chance(&mut self, numerator: usize, denominator: usize) -> bool {
+        self.below(denominator) < numerator
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+/// Returns a distinct, capitalized, letters-only name for each index (A, B, ..., Z, Aa, Ab, ...).
+fn name(index: usize) -> String {
+    let mut name = wire_name(index);
+    name[..1].make_ascii_uppercase();
+
+    name
+}
+
+/// Returns a distinct lowercase name for each index (a, b, ..., z, aa, ab, ...).
+fn wire_name(mut index: usize) -> String {
+    let mut reversed = Vec::new();
+
+    loop {
+        reversed.push(b'a' + (index % 26) as u8);
+
+        if index < 26 {
+            break;
+        }
+
+        index = index / 26 - 1;
+    }
+
+    reversed.iter().rev().map(|&b| b as char).collect()
+}
+
+fn letters(rng: &mut Rng, len: usize, alphabet: &str) -> String {
+    let alphabet: Vec<char> = alphabet.chars().collect();
+
+    (0..len).map(|_| *rng.choose(&alphabet)).collect()
+}
+
+fn lines(size: usize, mut line: impl FnMut() -> String) -> String {
+    (0..size).map(|_| line() + "\n").collect()
+}
+
+fn directions(rng: &mut Rng, size: usize) -> String {
+    letters(rng, size, "()")
+}
+
+fn presents(rng: &mut Rng, size: usize) -> String {
+    lines(size, || format!("{}x{}x{}", rng.range(1..=30), rng.range(1..=30), rng.range(1..=30)))
+}
+
+fn moves(rng: &mut Rng, size: usize) -> String {
+    letters(rng, size, "^v<>")
+}
+
+fn strings(rng: &mut Rng, size: usize) -> String {
+    lines(size, || letters(rng, 16, "abcdefghijklmnopqrstuvwxyz"))
+}
+
+fn light_instructions(rng: &mut Rng, size: usize) -> String {
+    lines(size, || {
+        let operation = *rng.choose(&["turn on", "turn off", "toggle"]);
+        let (x1, x2) = (rng.range(0..=999), rng.range(0..=999));
+        let (y1, y2) = (rng.range(0..=999), rng.range(0..=999));
+
+        format!("{} {},{} through {},{}", operation, x1.min(x2), y1.min(y2), x1.max(x2), y1.max(y2))
+    })
+}
+
+fn circuit(rng: &mut Rng, size: usize) -> String {
+    // Wires are created in dependency order so the circuit has no cycles; the first wire is "b"
+    // (which part two overrides) and the last is "a" (whose signal is the answer)
+    let names: Vec<String> = (0..size)
+        .map(|i| match i {
+            0 => String::from("b"),
+            i if i == size - 1 => String::from("a"),
+            i => wire_name(i + 1),
+        })
+        .collect();
+
+    let mut connections: Vec<String> = (0..size)
+        .map(|i| {
+            if i == 0 {
+                return format!("{} -> {}", rng.range(0..=u16::MAX as i32), names[i]);
+            }
+
+            let wire = |rng: &mut Rng| names[rng.below(i)].clone();
+
+            let input = match rng.below(6) {
+                0 => wire(rng),
+                1 => format!("NOT {}", wire(rng)),
+                2 if rng.chance(1, 4) => format!("1 AND {}", wire(rng)),
+                2 => format!("{} AND {}", wire(rng), wire(rng)),
+                3 => format!("{} OR {}", wire(rng), wire(rng)),
+                4 => format!("{} LSHIFT {}", wire(rng), rng.range(1..=15)),
+                _ => format!("{} RSHIFT {}", wire(rng), rng.range(1..=15)),
+            };
+
+            format!("{} -> {}", input, names[i])
+        })
+        .collect();
+
+    rng.shuffle(&mut connections);
+    connections.iter().map(|connection| format!("{}\n", connection)).collect()
+}
+
+fn escaped_strings(rng: &mut Rng, size: usize) -> String {
+    lines(size, || {
+        let mut string = String::from('"');
+
+        for _ in 0..rng.range(0..=30) {
+            match rng.below(10) {
+                0 => string.push_str(r#"\\"#),
+                1 => string.push_str(r#"\""#),
+                2 => string.push_str(&format!(r"\x{:02x}", rng.below(256))),
+                _ => string.push_str(&letters(rng, 1, "abcdefghijklmnopqrstuvwxyz")),
+            }
+        }
+
+        string.push('"');
+        string
+    })
+}
+
+fn distances(rng: &mut Rng, size: usize) -> String {
+    let mut entries = String::new();
+
+    for a in 0..size {
+        for b in a + 1..size {
+            entries.push_str(&format!("{} to {} = {}\n", name(a), name(b), rng.range(1..=150)));
+        }
+    }
+
+    entries
+}
+
+fn json_document(rng: &mut Rng, size: usize) -> String {
+    let mut remaining_numbers = size;
+    let mut elements = Vec::new();
+
+    while remaining_numbers > 0 {
+        elements.push(json_value(rng, 0, &mut remaining_numbers));
+    }
+
+    format!("[{}]", elements.join(","))
+}
+
+fn json_value(rng: &mut Rng, depth: usize, remaining_numbers: &mut usize) -> String {
+    const STRINGS: [&str; 6] = ["red", "orange", "yellow", "green", "blue", "violet"];
+
+    match rng.below(if depth < 4 { 4 } else { 2 }) {
+        0 if *remaining_numbers > 0 => {
+            *remaining_numbers -= 1;
+            rng.range(-100..=200).to_string()
+        },
+        0 | 1 => format!("{:?}", rng.choose(&STRINGS)),
+        2 => {
+            let elements: Vec<String> = (0..rng.range(1..=5))
+                .map(|_| json_value(rng, depth + 1, remaining_numbers))
+                .collect();
+
+            format!("[{}]", elements.join(","))
+        },
+        _ => {
+            let members: Vec<String> = (0..rng.range(1..=5))
+                .map(|i| format!("\"{}\":{}", wire_name(i as usize), json_value(rng, depth + 1, remaining_numbers)))
+                .collect();
+
+            format!("{{{}}}", members.join(","))
+        },
+    }
+}
+
+fn happiness_rules(rng: &mut Rng, size: usize) -> String {
+    let mut rules = String::new();
+
+    for subject in 0..size {
+        for neighbor in (0..size).filter(|&neighbor| neighbor != subject) {
+            let change = rng.range(-100..=100);
+
+            rules.push_str(&format!("{} would {} {} happiness units by sitting next to {}.\n",
+                                    name(subject), if change < 0 { "lose" } else { "gain" }, change.abs(),
+                                    name(neighbor)));
+        }
+    }
+
+    rules
+}
+
+fn reindeer(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|i| format!("{} can fly {} km/s for {} seconds, but then must rest for {} seconds.\n",
+                         name(i), rng.range(1..=30), rng.range(1..=20), rng.range(1..=200)))
+        .collect()
+}
+
+fn ingredients(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|i| format!("{}: capacity {}, durability {}, flavor {}, texture {}, calories {}\n",
+                         name(i), rng.range(-5..=5), rng.range(-5..=5), rng.range(-5..=5), rng.range(-5..=5),
+                         rng.range(1..=9)))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{runner, ParseMode, Solution};
+    use crate::runner::DAYS;
+
+    #[test]
+    fn test_generate_is_reproducible() {
+        for day in DAYS {
+            let size = default_size(day).min(20);
+
+            assert_eq!(generate(day, size, 17).unwrap(), generate(day, size, 17).unwrap());
+            assert_ne!(generate(day, size, 17).unwrap(), generate(day, size, 18).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_generate_size_limits() {
+        assert!(generate(7, 1, 0).is_err());
+        assert!(generate(11, 14, 0).is_err());
+        assert!(generate(16, 10, 0).is_err());
+    }
+
+    #[test]
+    fn test_generated_inputs_parse() {
+        fn parse<S: Solution>(input: &str) -> Result<(), Box<dyn Error>> {
+            S::parse(input).map(|_| ())
+        }
+
+        for day in DAYS {
+            let input = generate(day, default_size(day), 0).unwrap();
+
+            let result = match day {
+                1 => parse::<crate::day01::Day01>(&input),
+                2 => parse::<crate::day02::Day02>(&input),
+                3 => parse::<crate::day03::Day03>(&input),
+                4 => parse::<crate::day04::Day04>(&input),
+                5 => parse::<crate::day05::Day05>(&input),
+                6 => parse::<crate::day06::Day06>(&input),
+                7 => parse::<crate::day07::Day07>(&input),
+                8 => parse::<crate::day08::Day08>(&input),
+                9 => parse::<crate::day09::Day09>(&input),
+                10 => parse::<crate::day10::Day10>(&input),
+                11 => parse::<crate::day11::Day11>(&input),
+                12 => parse::<crate::day12::Day12>(&input),
+                13 => parse::<crate::day13::Day13>(&input),
+                14 => parse::<crate::day14::Day14>(&input),
+                15 => parse::<crate::day15::Day15>(&input),
+                _ => unreachable!(),
+            };
+
+            assert!(result.is_ok(), "Day {}: {:?}", day, result.err());
+
+            // Solving the remaining days is fast enough at their default sizes, or at small sizes for
+            // the days that try every permutation of their input
+            let result = match day {
+                4 | 10 | 11 => continue,
+                9 | 13 => runner::run(day, &generate(day, 6, 0).unwrap(), None, ParseMode::Strict),
+                _ => runner::run(day, &input, None, ParseMode::Strict),
+            };
+
+            assert!(result.is_ok(), "Day {}: {:?}", day, result.err());
+        }
+    }
+
+    #[test]
+    fn test_circuit() {
+        let input = generate(7, 1000, 0).unwrap();
+        let mut circuit = crate::day07::Day07::parse(&input).unwrap();
+
+        assert_eq!(1000, input.lines().count());
+        assert!(circuit.emulate().contains_key("a"));
+    }
+
+    #[test]
+    fn test_wire_name() {
+        assert_eq!("a", wire_name(0));
+        assert_eq!("z", wire_name(25));
+        assert_eq!("aa", wire_name(26));
+        assert_eq!("ba", wire_name(52));
+        assert_eq!("Zz", name(701));
+    }
+}
@@ -0,0 +1,372 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, AddAssign};
+
+/// A point on a two-dimensional grid; `x` increases to the east and `y` increases to the north.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct Point(pub i32, pub i32);
+
+impl Point {
+    /// Returns the four points orthogonally adjacent to this point.
+    pub fn neighbours(self) -> [Point; 4] {
+        [
+            self + Point(0, 1),
+            self + Point(1, 0),
+            self + Point(0, -1),
+            self + Point(-1, 0),
+        ]
+    }
+
+    /// Returns the eight points orthogonally or diagonally adjacent to this point.
+    pub fn neighbours_with_diagonals(self) -> [Point; 8] {
+        [
+            self + Point(0, 1),
+            self + Point(1, 1),
+            self + Point(1, 0),
+            self + Point(1, -1),
+            self + Point(0, -1),
+            self + Point(-1, -1),
+            self + Point(-1, 0),
+            self + Point(-1, 1),
+        ]
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, rhs: Point) -> Self::Output {
+        Point(self.0 + rhs.0, self.1 + rhs.1)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, rhs: Point) {
+        self.0 += rhs.0;
+        self.1 += rhs.1;
+    }
+}
+
+/// A rectangle of points, including both corners.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Rect {
+    pub min: Point,
+    pub max: Point,
+}
+
+impl Rect {
+    /// Creates the smallest rectangle containing both of the given points.
+    pub fn new(a: Point, b: Point) -> Self {
+        Rect {
+            min: Point(a.0.min(b.0), a.1.min(b.1)),
+            max: Point(a.0.max(b.0), a.1.max(b.1)),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        (self.max.0 - self.min.0) as usize + 1
+    }
+
+    pub fn height(&self) -> usize {
+        (self.max.1 - self.min.1) as usize + 1
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        (self.min.0..=self.max.0).contains(&point.0) && (self.min.1..=self.max.1).contains(&point.1)
+    }
+
+    pub fn contains_rect(&self, rect: &Rect) -> bool {
+        self.contains(rect.min) && self.contains(rect.max)
+    }
+
+    /// Returns the smallest rectangle containing both this rectangle and the given point.
+    pub fn including(&self, point: Point) -> Self {
+        Rect {
+            min: Point(self.min.0.min(point.0), self.min.1.min(point.1)),
+            max: Point(self.max.0.max(point.0), self.max.1.max(point.1)),
+        }
+    }
+
+    /// Iterates over every point in this rectangle, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let Rect { min, max } = *self;
+
+        (min.1..=max.1).flat_map(move |y| (min.0..=max.0).map(move |x| Point(x, y)))
+    }
+}
+
+/// A fixed-size grid with a value for every point between `(0, 0)` and `(width - 1, height - 1)`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DenseGrid<T> {
+    cells: Vec<T>,
+
+    width: usize,
+    height: usize,
+}
+
+impl<T: Clone> DenseGrid<T> {
+    pub fn filled(width: usize, height: usize, value: T) -> Self {
+        DenseGrid {
+            cells: vec![value; width * height],
+            width,
+            height,
+        }
+    }
+}
+
+impl<T: Clone + Default> DenseGrid<T> {
+    pub fn new(width: usize, height: usize) -> Self {
+        Self::filled(width, height, T::default())
+    }
+}
+
+impl<T> DenseGrid<T> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the rectangle containing every point in this grid, or `None` if the grid has no
+    /// width or height.
+    pub fn bounds(&self) -> Option<Rect> {
+        if self.cells.is_empty() {
+            None
+        } else {
+            Some(Rect::new(Point(0, 0), Point(self.width as i32 - 1, self.height as i32 - 1)))
+        }
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        usize::try_from(point.0).is_ok_and(|x| x < self.width) && usize::try_from(point.1).is_ok_and(|y| y < self.height)
+    }
+
+    pub fn get(&self, point: Point) -> Result<&T, OutOfBounds> {
+        let index = self.index(point)?;
+        Ok(&self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Result<&mut T, OutOfBounds> {
+        let index = self.index(point)?;
+        Ok(&mut self.cells[index])
+    }
+
+    /// Applies the given function to every cell in the given rectangle, or returns an error
+    /// without modifying any cells if the rectangle doesn't fit within this grid.
+    pub fn update_rect(&mut self, rect: &Rect, mut update: impl FnMut(&mut T)) -> Result<(), OutOfBounds> {
+        if !self.contains(rect.min) || !self.contains(rect.max) {
+            let point = if self.contains(rect.min) { rect.max } else { rect.min };
+            return Err(OutOfBounds { point, bounds: self.bounds() });
+        }
+
+        for y in rect.min.1..=rect.max.1 {
+            let row_start = y as usize * self.width;
+
+            self.cells[row_start + rect.min.0 as usize..=row_start + rect.max.0 as usize]
+                .iter_mut()
+                .for_each(&mut update);
+        }
+
+        Ok(())
+    }
+
+    /// Returns the points orthogonally adjacent to the given point that lie within this grid.
+    pub fn neighbours(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        point.neighbours().into_iter()
+            .filter(|neighbour| self.contains(*neighbour))
+    }
+
+    /// Iterates over every cell in this grid, row by row.
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    /// Iterates over every point in this grid and its value, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.bounds().into_iter().flat_map(|bounds| bounds.points()).zip(self.cells.iter())
+    }
+
+    fn index(&self, point: Point) -> Result<usize, OutOfBounds> {
+        if self.contains(point) {
+            Ok((point.1 as usize * self.width) + point.0 as usize)
+        } else {
+            Err(OutOfBounds { point, bounds: self.bounds() })
+        }
+    }
+}
+
+/// An unbounded grid that only stores values for the points that have them.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point, T>,
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        SparseGrid { cells: HashMap::new() }
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.cells.get(&point)
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.cells.get_mut(&point)
+    }
+
+    pub fn insert(&mut self, point: Point, value: T) -> Option<T> {
+        self.cells.insert(point, value)
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        self.cells.contains_key(&point)
+    }
+
+    /// Returns the number of points with a value.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Returns the smallest rectangle containing every point with a value, or `None` if no points
+    /// have values.
+    pub fn bounds(&self) -> Option<Rect> {
+        let mut points = self.cells.keys();
+        let first = *points.next()?;
+
+        Some(points.fold(Rect::new(first, first), |bounds, point| bounds.including(*point)))
+    }
+
+    /// Iterates over every point with a value, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells.iter().map(|(point, value)| (*point, value))
+    }
+}
+
+impl<T: Default> SparseGrid<T> {
+    /// Returns the value at the given point, inserting a default value if the point has none.
+    pub fn entry(&mut self, point: Point) -> &mut T {
+        self.cells.entry(point).or_default()
+    }
+}
+
+impl<T> FromIterator<(Point, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Point, T)>>(cells: I) -> Self {
+        SparseGrid { cells: cells.into_iter().collect() }
+    }
+}
+
+impl<T> Extend<(Point, T)> for SparseGrid<T> {
+    fn extend<I: IntoIterator<Item = (Point, T)>>(&mut self, cells: I) {
+        self.cells.extend(cells);
+    }
+}
+
+/// An attempt to access a point outside of a fixed-size grid. The grid's bounds are `None` if the
+/// grid is empty.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct OutOfBounds {
+    pub point: Point,
+    pub bounds: Option<Rect>,
+}
+
+impl Display for OutOfBounds {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.bounds {
+            Some(bounds) => write!(f, "Point ({}, {}) is outside of the grid ({}, {}) to ({}, {})",
+                                   self.point.0, self.point.1,
+                                   bounds.min.0, bounds.min.1, bounds.max.0, bounds.max.1),
+            None => write!(f, "Point ({}, {}) is outside of the empty grid", self.point.0, self.point.1),
+        }
+    }
+}
+
+impl Error for OutOfBounds {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_rect_points() {
+        let rect = Rect::new(Point(1, 2), Point(0, 3));
+
+        assert_eq!(2, rect.width());
+        assert_eq!(2, rect.height());
+        assert_eq!(
+            vec![Point(0, 2), Point(1, 2), Point(0, 3), Point(1, 3)],
+            rect.points().collect::<Vec<Point>>()
+        );
+    }
+
+    #[test]
+    fn test_dense_grid() {
+        let mut grid: DenseGrid<u32> = DenseGrid::new(3, 2);
+
+        *grid.get_mut(Point(2, 1)).unwrap() = 7;
+        grid.update_rect(&Rect::new(Point(0, 0), Point(1, 1)), |value| *value += 1).unwrap();
+
+        assert_eq!(vec![1, 1, 0, 1, 1, 7], grid.values().copied().collect::<Vec<u32>>());
+        assert_eq!(Ok(&7), grid.get(Point(2, 1)));
+
+        assert_eq!(
+            Err(OutOfBounds { point: Point(3, 0), bounds: grid.bounds() }),
+            grid.get(Point(3, 0))
+        );
+
+        assert_eq!(
+            Err(OutOfBounds { point: Point(0, 2), bounds: grid.bounds() }),
+            grid.update_rect(&Rect::new(Point(0, 0), Point(0, 2)), |value| *value += 1)
+        );
+
+        assert_eq!(1, *grid.get(Point(0, 0)).unwrap());
+    }
+
+    #[test]
+    fn test_empty_dense_grid() {
+        for (width, height) in [(0, 3), (3, 0), (0, 0)] {
+            let mut grid: DenseGrid<u32> = DenseGrid::new(width, height);
+
+            assert_eq!(None, grid.bounds());
+            assert_eq!(Err(OutOfBounds { point: Point(0, 0), bounds: None }), grid.get(Point(0, 0)));
+            assert!(grid.get_mut(Point(-1, 0)).is_err());
+            assert!(grid.update_rect(&Rect::new(Point(0, 0), Point(0, 0)), |value| *value += 1).is_err());
+            assert_eq!(0, grid.neighbours(Point(0, 0)).count());
+            assert_eq!(0, grid.iter().count());
+        }
+
+        assert_eq!(
+            "Point (0, 0) is outside of the empty grid",
+            DenseGrid::<u32>::new(0, 3).get(Point(0, 0)).unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn test_dense_grid_neighbours() {
+        let grid: DenseGrid<u32> = DenseGrid::new(3, 3);
+
+        assert_eq!(vec![Point(0, 1), Point(1, 0)], grid.neighbours(Point(0, 0)).collect::<Vec<Point>>());
+        assert_eq!(4, grid.neighbours(Point(1, 1)).count());
+    }
+
+    #[test]
+    fn test_sparse_grid() {
+        let mut grid: SparseGrid<u32> = SparseGrid::new();
+        assert_eq!(None, grid.bounds());
+
+        *grid.entry(Point(-2, 3)) += 1;
+        *grid.entry(Point(-2, 3)) += 1;
+        *grid.entry(Point(4, -1)) += 1;
+
+        assert_eq!(2, grid.len());
+        assert_eq!(Some(&2), grid.get(Point(-2, 3)));
+        assert_eq!(None, grid.get(Point(0, 0)));
+        assert_eq!(Some(Rect::new(Point(-2, -1), Point(4, 3))), grid.bounds());
+    }
+}
@@ -0,0 +1,175 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, Cursor};
+use std::path::{Path, PathBuf};
+
+/// The name of the manifest, within an input directory, that holds inline puzzle inputs (like the
+/// keys, seeds, and passwords for days 4, 10, and 11) keyed by day:
+///
+/// ```toml
+/// day04 = "abcdef"
+/// day10 = "1113122113"
+/// ```
+pub const MANIFEST_FILE: &str = "inline.toml";
+
+pub const DEFAULT_INPUT_DIR: &str = "inputs";
+
+/// A local directory of puzzle inputs, with a `dayNN.txt` file for each day with file input and
+/// an optional manifest for days with inline input.
+#[derive(Clone, Debug)]
+pub struct InputStore {
+    dir: PathBuf,
+}
+
+impl InputStore {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        InputStore { dir: dir.into() }
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    pub fn input_path(&self, day: u8) -> PathBuf {
+        self.dir.join(format!("day{:02}.txt", day))
+    }
+
+    pub fn manifest_path(&self) -> PathBuf {
+        self.dir.join(MANIFEST_FILE)
+    }
+
+    /// Returns the puzzle input for the given day, preferring the day's input file and falling
+    /// back to the day's entry in the manifest.
+    pub fn resolve(&self, day: u8) -> Result<String, InputError> {
+        let path = self.input_path(day);
+
+        match fs::read_to_string(&path) {
+            Ok(input) => return Ok(input),
+            Err(error) if error.kind() != io::ErrorKind::NotFound => return Err(InputError::Unreadable { path, error }),
+            Err(_) => {}
+        }
+
+        self.inline_input(day)?
+            .ok_or(InputError::Missing { day, path, manifest_path: self.manifest_path() })
+    }
+
+    /// Like [`InputStore::resolve`], but opens the day's input file for reading incrementally
+    /// instead of reading it into memory.
+    pub fn open(&self, day: u8) -> Result<Box<dyn BufRead>, InputError> {
+        let path = self.input_path(day);
+
+        match File::open(&path) {
+            Ok(file) => return Ok(Box::new(BufReader::new(file))),
+            Err(error) if error.kind() != io::ErrorKind::NotFound => return Err(InputError::Unreadable { path, error }),
+            Err(_) => {}
+        }
+
+        self.inline_input(day)?
+            .map(|input| Box::new(Cursor::new(input.into_bytes())) as Box<dyn BufRead>)
+            .ok_or(InputError::Missing { day, path, manifest_path: self.manifest_path() })
+    }
+
+    fn inline_input(&self, day: u8) -> Result<Option<String>, InputError> {
+        let manifest_path = self.manifest_path();
+
+        let manifest = match fs::read_to_string(&manifest_path) {
+            Ok(manifest) => manifest,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(error) => return Err(InputError::Unreadable { path: manifest_path, error }),
+        };
+
+        let manifest = manifest.parse::<toml::Table>()
+            .map_err(|error| InputError::MalformedManifest { path: manifest_path.clone(), message: error.to_string() })?;
+
+        match manifest.get(&format!("day{:02}", day)) {
+            Some(toml::Value::String(input)) => Ok(Some(input.clone())),
+            Some(toml::Value::Integer(input)) => Ok(Some(input.to_string())),
+            Some(_) => Err(InputError::MalformedManifest {
+                path: manifest_path,
+                message: format!("input for day{:02} must be a string or integer", day),
+            }),
+            None => Ok(None),
+        }
+    }
+}
+
+impl Default for InputStore {
+    fn default() -> Self {
+        InputStore::new(DEFAULT_INPUT_DIR)
+    }
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    Missing { day: u8, path: PathBuf, manifest_path: PathBuf },
+    Unreadable { path: PathBuf, error: io::Error },
+    MalformedManifest { path: PathBuf, message: String },
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::Missing { day, path, manifest_path } =>
+                write!(f, "No input for day {}; expected {} or a \"day{:02}\" entry in {}",
+                       day, path.display(), day, manifest_path.display()),
+            InputError::Unreadable { path, error } =>
+                write!(f, "Could not read {}: {}", path.display(), error),
+            InputError::MalformedManifest { path, message } =>
+                write!(f, "Could not parse {}: {}", path.display(), message),
+        }
+    }
+}
+
+impl Error for InputError {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn test_store(name: &str) -> InputStore {
+        let dir = std::env::temp_dir().join(format!("aoc-inputs-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+
+        InputStore::new(dir)
+    }
+
+    #[test]
+    fn test_resolve() {
+        let store = test_store("resolve");
+        fs::write(store.input_path(1), "(()))").unwrap();
+        fs::write(store.manifest_path(), "day04 = \"abcdef\"\nday10 = 1").unwrap();
+
+        assert_eq!("(()))", store.resolve(1).unwrap());
+        assert_eq!("abcdef", store.resolve(4).unwrap());
+        assert_eq!("1", store.resolve(10).unwrap());
+
+        let mut input = String::new();
+        store.open(1).unwrap().read_to_string(&mut input).unwrap();
+        assert_eq!("(()))", input);
+
+        input.clear();
+        store.open(4).unwrap().read_to_string(&mut input).unwrap();
+        assert_eq!("abcdef", input);
+        assert!(store.open(2).is_err());
+
+        let error = store.resolve(2).unwrap_err();
+        assert!(matches!(error, InputError::Missing { day: 2, .. }));
+        assert!(error.to_string().contains("day02.txt"));
+
+        fs::remove_dir_all(store.dir()).unwrap();
+    }
+
+    #[test]
+    fn test_resolve_without_manifest() {
+        let store = test_store("no-manifest");
+
+        assert!(matches!(store.resolve(4), Err(InputError::Missing { day: 4, .. })));
+
+        fs::write(store.manifest_path(), "day04 = [1, 2]").unwrap();
+        assert!(matches!(store.resolve(4), Err(InputError::MalformedManifest { .. })));
+
+        fs::remove_dir_all(store.dir()).unwrap();
+    }
+}
@@ -0,0 +1,236 @@
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+use std::io::BufRead;
+
+pub mod bench;
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod error;
+pub mod generate;
+pub mod grid;
+pub mod inputs;
+pub mod runner;
+pub mod stream;
+pub mod verify;
+
+/// A solution to a single day's puzzle. Implementations parse the raw puzzle input (either the
+/// contents of an input file or an inline value like a key or seed) once, then derive each part's
+/// answer from the parsed input.
+pub trait Solution {
+    type Input;
+
+    /// Parses puzzle input, failing on the first malformed line.
+    fn parse(input: &str) -> Result<Self::Input, Box<dyn Error>>;
+
+    /// Parses puzzle input, skipping malformed lines instead of failing where the puzzle allows
+    /// it.
+    fn parse_lenient(input: &str) -> Result<Lenient<Self::Input>, Box<dyn Error>> {
+        Ok(Lenient { input: Self::parse(input)?, skipped: Vec::new() })
+    }
+
+    fn part_one(input: &Self::Input) -> Answer;
+
+    /// Returns the answer to the second part of the puzzle, or `None` if this solution doesn't
+    /// (yet) solve the second part.
+    fn part_two(_input: &Self::Input) -> Option<Answer> {
+        None
+    }
+
+    /// Returns additional analysis of the puzzle input beyond the answers themselves, if this
+    /// solution offers any.
+    fn reports(_input: &Self::Input) -> Vec<Report> {
+        Vec::new()
+    }
+}
+
+/// A solution that can read its puzzle input incrementally and solve the selected part (or both
+/// parts, if no part is selected) in a single pass, so arbitrarily large inputs can be solved
+/// without holding the whole input in memory.
+pub trait Streaming: Solution {
+    fn solve_streaming(input: &mut dyn BufRead, part: Option<Part>, mode: ParseMode) -> Result<Streamed, Box<dyn Error>>;
+}
+
+/// A solution that can read and parse its puzzle input incrementally, for solutions whose parsed
+/// input is much smaller than their puzzle input but needs to be held in memory to solve either
+/// part.
+pub trait StreamingParse: Solution {
+    fn parse_streaming(input: &mut dyn BufRead, mode: ParseMode) -> Result<Lenient<Self::Input>, Box<dyn Error>>;
+}
+
+/// The answers to the selected parts of a puzzle solved from streamed input, along with an error
+/// for each line that was skipped in lenient mode. A part's answer is `None` if it wasn't selected
+/// or the solution doesn't solve it.
+#[derive(Debug)]
+pub struct Streamed {
+    pub part_one: Option<Answer>,
+    pub part_two: Option<Answer>,
+    pub skipped: Vec<Box<dyn Error>>,
+}
+
+/// Puzzle input parsed in lenient mode, along with an error for each line that was skipped.
+#[derive(Debug)]
+pub struct Lenient<T> {
+    pub input: T,
+    pub skipped: Vec<Box<dyn Error>>,
+}
+
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum ParseMode {
+    #[default]
+    Strict,
+    Lenient,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl TryFrom<u8> for Part {
+    type Error = Box<dyn Error>;
+
+    fn try_from(part: u8) -> Result<Self, Self::Error> {
+        match part {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            _ => Err(format!("No such part: {}", part).into()),
+        }
+    }
+}
+
+impl Part {
+    /// Returns true if this part is the given selected part, or if no part is selected.
+    pub fn is_selected(self, selected: Option<Part>) -> bool {
+        selected.is_none_or(|selected| selected == self)
+    }
+}
+
+impl From<Part> for u8 {
+    fn from(part: Part) -> Self {
+        match part {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", u8::from(*self))
+    }
+}
+
+/// The answer to one part of a puzzle, along with any supporting data (like the route or seating
+/// order that produced the answer) that may be of interest.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Answer {
+    pub value: Value,
+    pub details: Vec<(&'static str, Value)>,
+}
+
+impl Answer {
+    pub fn with_detail(mut self, name: &'static str, value: impl Into<Value>) -> Self {
+        self.details.push((name, value.into()));
+        self
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+/// A titled section of additional analysis of a puzzle's input, made up of labeled values.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Report {
+    pub title: &'static str,
+    pub entries: Vec<(String, Value)>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Value {
+    Integer(i128),
+    Text(String),
+    List(Vec<Value>),
+}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Value::Integer(value) => write!(f, "{}", value),
+            Value::Text(value) => write!(f, "{}", value),
+            Value::List(values) => write!(f, "{}", values.iter().map(Value::to_string).collect::<Vec<String>>().join(", ")),
+        }
+    }
+}
+
+macro_rules! integer_value {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Value {
+                fn from(value: $t) -> Self {
+                    Value::Integer(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+integer_value!(i32, i64, u16, u32, u64, usize);
+
+impl From<u128> for Value {
+    fn from(value: u128) -> Self {
+        // Values too large for an i128 are kept as text so they're never silently truncated
+        match i128::try_from(value) {
+            Ok(value) => Value::Integer(value),
+            Err(_) => Value::Text(value.to_string()),
+        }
+    }
+}
+
+impl From<String> for Value {
+    fn from(value: String) -> Self {
+        Value::Text(value)
+    }
+}
+
+impl From<&str> for Value {
+    fn from(value: &str) -> Self {
+        Value::Text(String::from(value))
+    }
+}
+
+impl<T: Into<Value>> From<Vec<T>> for Value {
+    fn from(values: Vec<T>) -> Self {
+        Value::List(values.into_iter().map(T::into).collect())
+    }
+}
+
+macro_rules! plain_answer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer { value: value.into(), details: Vec::new() }
+                }
+            }
+        )*
+    };
+}
+
+plain_answer!(i32, i64, u16, u32, u64, usize, String);
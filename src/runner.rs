@@ -0,0 +1,275 @@
+use std::error::Error;
+use std::io::BufRead;
+use std::ops::RangeInclusive;
+use std::time::{Duration, Instant};
+use crate::{Answer, Lenient, ParseMode, Part, Report, Solution, Streamed, Streaming, StreamingParse};
+use crate::day01::Day01;
+use crate::day02::Day02;
+use crate::day03::Day03;
+use crate::day04::Day04;
+use crate::day05::Day05;
+use crate::day06::Day06;
+use crate::day07::Day07;
+use crate::day08::Day08;
+use crate::day09::Day09;
+use crate::day10::Day10;
+use crate::day11::Day11;
+use crate::day12::Day12;
+use crate::day13::Day13;
+use crate::day14::Day14;
+use crate::day15::Day15;
+
+pub const DAYS: RangeInclusive<u8> = 1..=15;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PartResult {
+    pub day: u8,
+    pub part: Part,
+    pub answer: Answer,
+    pub elapsed: Duration,
+}
+
+/// The results of solving a single day's puzzle, along with any input lines skipped when parsing the
+/// input in lenient mode.
+#[derive(Debug)]
+pub struct DayResult {
+    pub day: u8,
+    pub parse_time: Duration,
+    pub skipped: Vec<Box<dyn Error>>,
+    pub parts: Vec<PartResult>,
+}
+
+/// Parses the given input for the given day and solves either the selected part or, if no part is
+/// selected, every part the day's solution supports. The results will not include a part the day's
+/// solution doesn't support, even if that part was selected.
+pub fn run(day: u8, input: &str, part: Option<Part>, mode: ParseMode) -> Result<DayResult, Box<dyn Error>> {
+    match day {
+        1 => run_solution::<Day01>(day, input, part, mode),
+        2 => run_solution::<Day02>(day, input, part, mode),
+        3 => run_solution::<Day03>(day, input, part, mode),
+        4 => run_solution::<Day04>(day, input, part, mode),
+        5 => run_solution::<Day05>(day, input, part, mode),
+        6 => run_solution::<Day06>(day, input, part, mode),
+        7 => run_solution::<Day07>(day, input, part, mode),
+        8 => run_solution::<Day08>(day, input, part, mode),
+        9 => run_solution::<Day09>(day, input, part, mode),
+        10 => run_solution::<Day10>(day, input, part, mode),
+        11 => run_solution::<Day11>(day, input, part, mode),
+        12 => run_solution::<Day12>(day, input, part, mode),
+        13 => run_solution::<Day13>(day, input, part, mode),
+        14 => run_solution::<Day14>(day, input, part, mode),
+        15 => run_solution::<Day15>(day, input, part, mode),
+        _ => Err(format!("No solution for day {}", day).into()),
+    }
+}
+
+/// Parses the given input for the given day and returns any additional analysis of the input the
+/// day's solution offers.
+pub fn reports(day: u8, input: &str, mode: ParseMode) -> Result<Vec<Report>, Box<dyn Error>> {
+    match day {
+        1 => report_solution::<Day01>(input, mode),
+        2 => report_solution::<Day02>(input, mode),
+        3 => report_solution::<Day03>(input, mode),
+        4 => report_solution::<Day04>(input, mode),
+        5 => report_solution::<Day05>(input, mode),
+        6 => report_solution::<Day06>(input, mode),
+        7 => report_solution::<Day07>(input, mode),
+        8 => report_solution::<Day08>(input, mode),
+        9 => report_solution::<Day09>(input, mode),
+        10 => report_solution::<Day10>(input, mode),
+        11 => report_solution::<Day11>(input, mode),
+        12 => report_solution::<Day12>(input, mode),
+        13 => report_solution::<Day13>(input, mode),
+        14 => report_solution::<Day14>(input, mode),
+        15 => report_solution::<Day15>(input, mode),
+        _ => Err(format!("No solution for day {}", day).into()),
+    }
+}
+
+fn report_solution<S: Solution>(input: &str, mode: ParseMode) -> Result<Vec<Report>, Box<dyn Error>> {
+    let input = match mode {
+        ParseMode::Strict => S::parse(input)?,
+        ParseMode::Lenient => S::parse_lenient(input)?.input,
+    };
+
+    Ok(S::reports(&input))
+}
+
+/// Like [`run`], but reads the input incrementally for days whose solutions support streaming,
+/// solving the selected parts in a single pass without holding the whole input in memory. For
+/// those days, parsing and solving are interleaved, so the parse time is zero and each part's
+/// elapsed time covers the whole pass. Days whose parsed input must be held in memory are parsed
+/// incrementally and then solved and timed one part at a time, and other days' input is read into
+/// memory and solved as usual.
+pub fn run_streaming(day: u8, input: &mut dyn BufRead, part: Option<Part>, mode: ParseMode) -> Result<DayResult, Box<dyn Error>> {
+    match day {
+        1 => stream_solution::<Day01>(day, input, part, mode),
+        2 => stream_solution::<Day02>(day, input, part, mode),
+        3 => stream_solution::<Day03>(day, input, part, mode),
+        5 => stream_solution::<Day05>(day, input, part, mode),
+        6 => stream_solution::<Day06>(day, input, part, mode),
+        7 => stream_parse_solution::<Day07>(day, input, part, mode),
+        8 => stream_solution::<Day08>(day, input, part, mode),
+        9 => stream_parse_solution::<Day09>(day, input, part, mode),
+        12 => stream_solution::<Day12>(day, input, part, mode),
+        13 => stream_parse_solution::<Day13>(day, input, part, mode),
+        14 => stream_parse_solution::<Day14>(day, input, part, mode),
+        15 => stream_parse_solution::<Day15>(day, input, part, mode),
+        _ => {
+            let mut string = String::new();
+            input.read_to_string(&mut string)?;
+
+            run(day, &string, part, mode)
+        },
+    }
+}
+
+fn stream_solution<S: Streaming>(day: u8, input: &mut dyn BufRead, part: Option<Part>, mode: ParseMode) -> Result<DayResult, Box<dyn Error>> {
+    let start = Instant::now();
+    let Streamed { part_one, part_two, skipped } = S::solve_streaming(input, part, mode)?;
+    let elapsed = start.elapsed();
+
+    let parts = [(Part::One, part_one), (Part::Two, part_two)].into_iter()
+        .filter(|(solved_part, _)| solved_part.is_selected(part))
+        .filter_map(|(part, answer)| answer.map(|answer| PartResult { day, part, answer, elapsed }))
+        .collect();
+
+    Ok(DayResult { day, parse_time: Duration::ZERO, skipped, parts })
+}
+
+fn stream_parse_solution<S: StreamingParse>(day: u8, input: &mut dyn BufRead, part: Option<Part>, mode: ParseMode) -> Result<DayResult, Box<dyn Error>> {
+    let start = Instant::now();
+    let Lenient { input, skipped } = S::parse_streaming(input, mode)?;
+    let parse_time = start.elapsed();
+
+    Ok(DayResult { day, parse_time, skipped, parts: solve_parts::<S>(day, &input, part) })
+}
+
+fn run_solution<S: Solution>(day: u8, input: &str, part: Option<Part>, mode: ParseMode) -> Result<DayResult, Box<dyn Error>> {
+    let start = Instant::now();
+
+    let Lenient { input, skipped } = match mode {
+        ParseMode::Strict => Lenient { input: S::parse(input)?, skipped: Vec::new() },
+        ParseMode::Lenient => S::parse_lenient(input)?,
+    };
+
+    let parse_time = start.elapsed();
+
+    Ok(DayResult { day, parse_time, skipped, parts: solve_parts::<S>(day, &input, part) })
+}
+
+fn solve_parts<S: Solution>(day: u8, input: &S::Input, part: Option<Part>) -> Vec<PartResult> {
+    let mut results = Vec::new();
+
+    if Part::One.is_selected(part) {
+        let start = Instant::now();
+        let answer = S::part_one(input);
+
+        results.push(PartResult { day, part: Part::One, answer, elapsed: start.elapsed() });
+    }
+
+    if Part::Two.is_selected(part) {
+        let start = Instant::now();
+
+        if let Some(answer) = S::part_two(input) {
+            results.push(PartResult { day, part: Part::Two, answer, elapsed: start.elapsed() });
+        }
+    }
+
+    results
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::io::Cursor;
+    use crate::{generate, Value};
+
+    #[test]
+    fn test_run() {
+        let answers = |result: DayResult| -> Vec<(u8, Part, Answer)> {
+            result.parts.into_iter()
+                .map(|result| (result.day, result.part, result.answer))
+                .collect()
+        };
+
+        assert_eq!(
+            vec![(1, Part::One, Answer::from(-1)), (1, Part::Two, Answer::from(3usize))],
+            answers(run(1, "())", None, ParseMode::Strict).unwrap())
+        );
+
+        assert_eq!(
+            vec![(1, Part::Two, Answer::from(3usize))],
+            answers(run(1, "())", Some(Part::Two), ParseMode::Strict).unwrap())
+        );
+
+        assert!(run(12, "[1,2,3]", Some(Part::Two), ParseMode::Strict).unwrap().parts.is_empty());
+        assert!(run(16, "", None, ParseMode::Strict).is_err());
+    }
+
+    #[test]
+    fn test_reports() {
+        let reports = reports(1, "(()))", ParseMode::Strict).unwrap();
+        assert_eq!(vec!["Extremes", "First step on each floor", "Steps spent on each floor"],
+                   reports.iter().map(|report| report.title).collect::<Vec<&str>>());
+
+        let reports = super::reports(2, "2x3x4", ParseMode::Strict).unwrap();
+        assert_eq!(vec!["Order totals", "Presents by slack"],
+                   reports.iter().map(|report| report.title).collect::<Vec<&str>>());
+
+        assert!(super::reports(5, "ugknbfddgicrmopn", ParseMode::Strict).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_run_streaming() {
+        // Days with inline input aren't streamed, and are slow to solve
+        for day in DAYS.filter(|day| ![4, 10, 11].contains(day)) {
+            let input = generate::generate(day, generate::default_size(day).min(6), 0).unwrap();
+
+            // Details like routes may differ between equally-good answers, so only compare values
+            let answers = |result: DayResult| -> Vec<(Part, Value)> {
+                result.parts.into_iter().map(|result| (result.part, result.answer.value)).collect()
+            };
+
+            assert_eq!(
+                answers(run(day, &input, None, ParseMode::Strict).unwrap()),
+                answers(run_streaming(day, &mut Cursor::new(&input), None, ParseMode::Strict).unwrap()),
+                "Day {}", day
+            );
+        }
+
+        let result = run_streaming(2, &mut Cursor::new("2x3x4\n2x3\n1x1x10\n"), Some(Part::Two), ParseMode::Lenient).unwrap();
+        assert_eq!(vec![Part::Two], result.parts.iter().map(|result| result.part).collect::<Vec<Part>>());
+        assert_eq!(1, result.skipped.len());
+    }
+
+    #[test]
+    fn test_run_streaming_selected_part() {
+        let parts = |result: DayResult| -> Vec<Part> { result.parts.iter().map(|result| result.part).collect() };
+
+        for day in DAYS.filter(|day| ![4, 10, 11].contains(day)) {
+            let input = generate::generate(day, generate::default_size(day).min(6), 0).unwrap();
+
+            for part in [Part::One, Part::Two] {
+                let streamed = run_streaming(day, &mut Cursor::new(&input), Some(part), ParseMode::Strict).unwrap();
+                assert_eq!(parts(run(day, &input, Some(part), ParseMode::Strict).unwrap()), parts(streamed), "Day {}", day);
+            }
+        }
+
+        let day03 = Day03::solve_streaming(&mut "^v".as_bytes(), Some(Part::Two), ParseMode::Strict).unwrap();
+        assert_eq!(None, day03.part_one);
+        assert_eq!(Some(Answer::from(3u32)), day03.part_two);
+    }
+
+    #[test]
+    fn test_run_lenient() {
+        let presents = "2x3x4\n2x3\n1x1x10\n";
+
+        assert!(run(2, presents, Some(Part::One), ParseMode::Strict).is_err());
+
+        let result = run(2, presents, Some(Part::One), ParseMode::Lenient).unwrap();
+        assert_eq!(Answer::from(101u32), result.parts[0].answer);
+        assert_eq!(1, result.skipped.len());
+        assert_eq!("Expected 3 dimensions, but found 2 at line 2, column 1: \"2x3\"", result.skipped[0].to_string());
+    }
+}
@@ -0,0 +1,272 @@
+use std::error::Error;
+use std::fmt::{Debug, Display};
+use std::io::{self, BufRead};
+use crate::ParseMode;
+use crate::error::ParseError;
+
+/// Reads the given input one line at a time, passing the 1-based line number and the line
+/// (without its line ending) to the given function. Only one line is held in memory at a time.
+pub(crate) fn for_each_line<E: From<io::Error>>(
+    input: &mut dyn BufRead,
+    mut each: impl FnMut(usize, &str) -> Result<(), E>,
+) -> Result<(), E> {
+    let mut line = String::new();
+    let mut line_number = 0;
+
+    loop {
+        line.clear();
+
+        if input.read_line(&mut line)? == 0 {
+            return Ok(());
+        }
+
+        line_number += 1;
+
+        let trimmed = match line.strip_suffix('\n') {
+            Some(trimmed) => trimmed.strip_suffix('\r').unwrap_or(trimmed),
+            None => &line,
+        };
+
+        each(line_number, trimmed)?;
+    }
+}
+
+/// Parses each line of the given input with the given function as it's read, passing each parsed
+/// line to the given consumer. Like [`crate::error::parse_lines`], parsing stops at the first
+/// malformed line in strict mode; in lenient mode, malformed lines are skipped and their errors
+/// are returned once the whole input has been read.
+pub(crate) fn parse_lines<T, K: Debug + Display + 'static>(
+    input: &mut dyn BufRead,
+    mode: ParseMode,
+    parse: impl Fn(&str) -> Result<T, ParseError<K>>,
+    mut each: impl FnMut(T),
+) -> Result<Vec<ParseError<K>>, Box<dyn Error>> {
+    let mut skipped = Vec::new();
+
+    for_each_line(input, |line_number, line| -> Result<(), Box<dyn Error>> {
+        match parse(line) {
+            Ok(item) => each(item),
+            Err(error) => match mode {
+                ParseMode::Strict => return Err(Box::new(error.at_line(line_number))),
+                ParseMode::Lenient => skipped.push(error.at_line(line_number)),
+            },
+        }
+
+        Ok(())
+    })?;
+
+    Ok(skipped)
+}
+
+/// Reads the given input one character at a time without holding more than a buffer's worth of
+/// input in memory, passing the byte offset and value of each character to the given function.
+/// Fails if the input isn't valid UTF-8.
+pub(crate) fn for_each_char<E: From<io::Error>>(
+    input: &mut dyn BufRead,
+    mut each: impl FnMut(usize, char) -> Result<(), E>,
+) -> Result<(), E> {
+    for_each_piece(input, |_| false, |offset, piece| match piece {
+        Piece::Char(c) => each(offset, c),
+        Piece::Run(_) => unreachable!("No bytes are read in bulk"),
+    })
+}
+
+/// Like [`for_each_char`], but ignores whitespace at the end of the input like [`str::trim_end`].
+/// Whitespace followed by other characters is passed along once the next character is read.
+pub(crate) fn for_each_char_trim_end<E: From<io::Error>>(
+    input: &mut dyn BufRead,
+    mut each: impl FnMut(usize, char) -> Result<(), E>,
+) -> Result<(), E> {
+    for_each_piece_trim_end(input, |_| false, |offset, piece| match piece {
+        Piece::Char(c) => each(offset, c),
+        Piece::Run(_) => unreachable!("No bytes are read in bulk"),
+    })
+}
+
+/// A piece of input read by [`for_each_piece`]: either a single character, or a run of bytes that
+/// were all accepted by the caller's bulk filter.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub(crate) enum Piece<'a> {
+    Char(char),
+    Run(&'a [u8]),
+}
+
+/// The number of bytes checked against a bulk filter at a time.
+const RUN_CHUNK_SIZE: usize = 128;
+
+/// Like [`for_each_char`], but passes chunks of input made up entirely of bytes accepted by the
+/// given filter as a single [`Piece::Run`] instead of one character at a time, for input that's
+/// mostly made up of a few characters. The filter must only accept ASCII bytes.
+pub(crate) fn for_each_piece<E: From<io::Error>>(
+    input: &mut dyn BufRead,
+    is_bulk: impl Fn(u8) -> bool,
+    mut each: impl FnMut(usize, Piece) -> Result<(), E>,
+) -> Result<(), E> {
+    let invalid_utf8 = || io::Error::new(io::ErrorKind::InvalidData, "stream did not contain valid UTF-8");
+
+    let mut offset = 0;
+    let mut partial_char = Vec::with_capacity(4);
+
+    loop {
+        let buffer = input.fill_buf()?;
+        let len = buffer.len();
+
+        if len == 0 {
+            break;
+        }
+
+        for chunk in buffer.chunks(RUN_CHUNK_SIZE) {
+            // Check the whole chunk without stopping early, which lets the check be vectorized
+            if partial_char.is_empty() && chunk.iter().fold(true, |bulk, &byte| bulk & is_bulk(byte)) {
+                each(offset, Piece::Run(chunk))?;
+                offset += chunk.len();
+
+                continue;
+            }
+
+            for &byte in chunk {
+                if partial_char.is_empty() && byte.is_ascii() {
+                    each(offset, Piece::Char(byte as char))?;
+                    offset += 1;
+
+                    continue;
+                }
+
+                // Multi-byte characters may span buffer boundaries, so accumulate bytes until we've
+                // got a whole character
+                partial_char.push(byte);
+
+                match std::str::from_utf8(&partial_char) {
+                    Ok(string) => {
+                        each(offset, Piece::Char(string.chars().next().unwrap()))?;
+                        offset += partial_char.len();
+                        partial_char.clear();
+                    },
+                    Err(error) if error.error_len().is_some() => return Err(invalid_utf8().into()),
+                    Err(_) => {},
+                }
+            }
+        }
+
+        input.consume(len);
+    }
+
+    if partial_char.is_empty() {
+        Ok(())
+    } else {
+        Err(invalid_utf8().into())
+    }
+}
+
+/// Like [`for_each_piece`], but ignores whitespace at the end of the input like [`str::trim_end`].
+/// Whitespace followed by other characters is passed along once the next piece is read. The bulk
+/// filter must not accept whitespace.
+pub(crate) fn for_each_piece_trim_end<E: From<io::Error>>(
+    input: &mut dyn BufRead,
+    is_bulk: impl Fn(u8) -> bool,
+    mut each: impl FnMut(usize, Piece) -> Result<(), E>,
+) -> Result<(), E> {
+    let mut pending_whitespace = Vec::new();
+
+    for_each_piece(input, is_bulk, |offset, piece| {
+        if let Piece::Char(c) = piece {
+            if c.is_whitespace() {
+                pending_whitespace.push((offset, c));
+                return Ok(());
+            }
+        }
+
+        for (offset, c) in pending_whitespace.drain(..) {
+            each(offset, Piece::Char(c))?;
+        }
+
+        each(offset, piece)
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::io::{BufReader, Cursor};
+
+    #[test]
+    fn test_for_each_line() {
+        let mut lines = Vec::new();
+
+        for_each_line(&mut Cursor::new("one\r\ntwo\n\nthree"), |number, line| -> io::Result<()> {
+            lines.push((number, String::from(line)));
+            Ok(())
+        }).unwrap();
+
+        assert_eq!(
+            vec![(1, "one"), (2, "two"), (3, ""), (4, "three")],
+            lines.iter().map(|(number, line)| (*number, line.as_str())).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        let parse = |line: &str| line.parse::<u32>()
+            .map_err(|_| ParseError::new("Not a number", line, line));
+
+        let mut parsed = Vec::new();
+        let skipped = parse_lines(&mut Cursor::new("1\ntwo\n3\n"), ParseMode::Lenient, parse, |n| parsed.push(n)).unwrap();
+
+        assert_eq!(vec![1, 3], parsed);
+        assert_eq!(vec![2], skipped.iter().map(|error| error.line).collect::<Vec<usize>>());
+
+        let error = parse_lines(&mut Cursor::new("1\ntwo\n3\n"), ParseMode::Strict, parse, |_| {}).unwrap_err();
+        assert_eq!("Not a number at line 2, column 1: \"two\"", error.to_string());
+    }
+
+    #[test]
+    fn test_for_each_char() {
+        // A tiny buffer forces multi-byte characters to span buffer boundaries
+        let mut input = BufReader::with_capacity(1, Cursor::new("a→b"));
+        let mut chars = Vec::new();
+
+        for_each_char(&mut input, |offset, c| -> io::Result<()> {
+            chars.push((offset, c));
+            Ok(())
+        }).unwrap();
+
+        assert_eq!(vec![(0, 'a'), (1, '→'), (4, 'b')], chars);
+
+        let mut input = Cursor::new(vec![b'a', 0xff, b'b']);
+        assert!(for_each_char(&mut input, |_, _| -> io::Result<()> { Ok(()) }).is_err());
+    }
+
+    #[test]
+    fn test_for_each_char_trim_end() {
+        let mut chars = Vec::new();
+
+        for_each_char_trim_end(&mut Cursor::new("a b \n"), |offset, c| -> io::Result<()> {
+            chars.push((offset, c));
+            Ok(())
+        }).unwrap();
+
+        assert_eq!(vec![(0, 'a'), (1, ' '), (2, 'b')], chars);
+    }
+
+    #[test]
+    fn test_for_each_piece_trim_end() {
+        let input = format!("{}x{} \n", "a".repeat(RUN_CHUNK_SIZE), "a".repeat(RUN_CHUNK_SIZE));
+        let mut pieces = Vec::new();
+
+        for_each_piece_trim_end(&mut Cursor::new(&input), |byte| byte == b'a', |offset, piece| -> io::Result<()> {
+            pieces.push(match piece {
+                Piece::Char(c) => (offset, c.to_string()),
+                Piece::Run(run) => (offset, format!("{} bytes", run.len())),
+            });
+
+            Ok(())
+        }).unwrap();
+
+        // The second chunk contains the 'x' and the last contains trailing whitespace, so they're
+        // read one character at a time
+        let mut expected = vec![(0, format!("{} bytes", RUN_CHUNK_SIZE)), (RUN_CHUNK_SIZE, String::from("x"))];
+        expected.extend((RUN_CHUNK_SIZE + 1..=2 * RUN_CHUNK_SIZE).map(|offset| (offset, String::from("a"))));
+
+        assert_eq!(expected, pieces);
+    }
+}
@@ -0,0 +1,136 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::str::FromStr;
+use crate::{Part, Value};
+use crate::runner::PartResult;
+
+/// Known-good answers for some or all parts of each day's puzzle, typically loaded from a TOML file
+/// with a table for each day and a key for each part:
+///
+/// ```toml
+/// [day01]
+/// part1 = 280
+/// part2 = 1797
+///
+/// [day11]
+/// part1 = "hxbxxyzz"
+/// ```
+#[derive(Debug, Default, Eq, PartialEq)]
+pub struct Answers {
+    expected: BTreeMap<(u8, Part), String>,
+}
+
+impl Answers {
+    pub fn expected(&self, day: u8, part: Part) -> Option<&str> {
+        self.expected.get(&(day, part)).map(String::as_str)
+    }
+
+    /// Returns the parts for which an answer is known, in day/part order.
+    pub fn parts(&self) -> impl Iterator<Item = (u8, Part)> + '_ {
+        self.expected.keys().copied()
+    }
+
+    pub fn check(&self, result: &PartResult) -> Outcome {
+        let actual = result.answer.value.to_string();
+
+        match self.expected(result.day, result.part) {
+            Some(expected) if expected == actual => Outcome::Pass,
+            Some(expected) => Outcome::Fail { expected: String::from(expected), actual },
+            None => Outcome::Missing { actual },
+        }
+    }
+}
+
+impl FromStr for Answers {
+    type Err = Box<dyn Error>;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let mut expected = BTreeMap::new();
+
+        for (day_key, parts) in string.parse::<toml::Table>()? {
+            let day = day_key.strip_prefix("day")
+                .and_then(|day| day.parse::<u8>().ok())
+                .ok_or_else(|| format!("Unexpected table \"{}\"; expected a table like \"day01\"", day_key))?;
+
+            let parts = parts.as_table()
+                .ok_or_else(|| format!("Expected \"{}\" to be a table", day_key))?;
+
+            for (part_key, answer) in parts {
+                let part = match part_key.as_str() {
+                    "part1" => Part::One,
+                    "part2" => Part::Two,
+                    _ => return Err(format!("Unexpected key \"{}\" in \"{}\"", part_key, day_key).into()),
+                };
+
+                let answer = match answer {
+                    toml::Value::Integer(answer) => Value::from(*answer).to_string(),
+                    toml::Value::String(answer) => answer.clone(),
+                    _ => return Err(format!("Answer for {}.{} must be an integer or string", day_key, part_key).into()),
+                };
+
+                expected.insert((day, part), answer);
+            }
+        }
+
+        Ok(Answers { expected })
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Outcome {
+    Pass,
+    Fail { expected: String, actual: String },
+    Missing { actual: String },
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::time::Duration;
+    use indoc::indoc;
+    use crate::Answer;
+
+    const TEST_ANSWERS: &str = indoc! {r#"
+        [day01]
+        part1 = 280
+        part2 = 1797
+
+        [day11]
+        part1 = "hxbxxyzz"
+    "#};
+
+    fn result(day: u8, part: Part, answer: Answer) -> PartResult {
+        PartResult { day, part, answer, elapsed: Duration::ZERO }
+    }
+
+    #[test]
+    fn test_answers_from_str() {
+        let answers = Answers::from_str(TEST_ANSWERS).unwrap();
+
+        assert_eq!(Some("280"), answers.expected(1, Part::One));
+        assert_eq!(Some("1797"), answers.expected(1, Part::Two));
+        assert_eq!(Some("hxbxxyzz"), answers.expected(11, Part::One));
+        assert_eq!(None, answers.expected(11, Part::Two));
+        assert_eq!(vec![(1, Part::One), (1, Part::Two), (11, Part::One)], answers.parts().collect::<Vec<_>>());
+
+        assert!(Answers::from_str("[dayone]\npart1 = 1").is_err());
+        assert!(Answers::from_str("[day01]\npart3 = 1").is_err());
+        assert!(Answers::from_str("[day01]\npart1 = 1.5").is_err());
+    }
+
+    #[test]
+    fn test_check() {
+        let answers = Answers::from_str(TEST_ANSWERS).unwrap();
+
+        assert_eq!(Outcome::Pass, answers.check(&result(1, Part::One, Answer::from(280))));
+        assert_eq!(
+            Outcome::Fail { expected: String::from("1797"), actual: String::from("1796") },
+            answers.check(&result(1, Part::Two, Answer::from(1796)))
+        );
+        assert_eq!(Outcome::Pass, answers.check(&result(11, Part::One, Answer::from(String::from("hxbxxyzz")))));
+        assert_eq!(
+            Outcome::Missing { actual: String::from("hxcaabcc") },
+            answers.check(&result(11, Part::Two, Answer::from(String::from("hxcaabcc"))))
+        );
+    }
+}